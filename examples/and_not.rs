@@ -30,7 +30,7 @@ fn main() {
     let or: Or = Or::default();
     let not: Not = Not::default();
     let id: Id<bool> = Id::default();
-    let fa: False1<bool> = False1::default();
+    let _fa: False1<bool> = False1::default();
 
     // `and[not] <=> or`
     assert_eq!(and.path(not), or);
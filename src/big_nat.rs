@@ -0,0 +1,112 @@
+//! Arbitrary-precision natural numbers.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops;
+
+/// An arbitrary-precision natural number.
+///
+/// There is no largest `BigNat`, so its dictionary has none of the edge cases at `max`
+/// that the unsigned integer types have, e.g. `∃even{(>= k)} => true_1`.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigNat {
+    // Digits in base `2^32`, least significant first, without trailing zeros.
+    digits: Vec<u32>,
+}
+
+impl BigNat {
+    /// Returns `true` if the number is zero.
+    pub fn is_zero(&self) -> bool {self.digits.is_empty()}
+
+    /// Returns `true` if the number is even.
+    pub fn is_even(&self) -> bool {
+        self.digits.first().map(|d| d % 2 == 0).unwrap_or(true)
+    }
+
+    // Removes trailing zeros, such that every number has a unique representation.
+    fn normalize(mut self) -> BigNat {
+        while self.digits.last() == Some(&0) {self.digits.pop();}
+        self
+    }
+
+    // Divides by a small number and returns the remainder.
+    fn div_rem_small(&self, d: u32) -> (BigNat, u32) {
+        let mut digits = vec![0; self.digits.len()];
+        let mut rem = 0u64;
+        for (i, &a) in self.digits.iter().enumerate().rev() {
+            let x = (rem << 32) | a as u64;
+            digits[i] = (x / d as u64) as u32;
+            rem = x % d as u64;
+        }
+        (BigNat {digits}.normalize(), rem as u32)
+    }
+}
+
+impl From<u8> for BigNat {
+    fn from(x: u8) -> BigNat {BigNat::from(x as u64)}
+}
+
+impl From<u32> for BigNat {
+    fn from(x: u32) -> BigNat {BigNat::from(x as u64)}
+}
+
+impl From<u64> for BigNat {
+    fn from(x: u64) -> BigNat {
+        BigNat {digits: vec![x as u32, (x >> 32) as u32]}.normalize()
+    }
+}
+
+impl Ord for BigNat {
+    fn cmp(&self, other: &BigNat) -> Ordering {
+        self.digits.len().cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigNat {
+    fn partial_cmp(&self, other: &BigNat) -> Option<Ordering> {Some(self.cmp(other))}
+}
+
+impl<'a> ops::Add<&'a BigNat> for &BigNat {
+    type Output = BigNat;
+    fn add(self, other: &'a BigNat) -> BigNat {
+        let n = self.digits.len().max(other.digits.len());
+        let mut digits = Vec::with_capacity(n + 1);
+        let mut carry = 0;
+        for i in 0..n {
+            let a = self.digits.get(i).cloned().unwrap_or(0) as u64;
+            let b = other.digits.get(i).cloned().unwrap_or(0) as u64;
+            let x = a + b + carry;
+            digits.push(x as u32);
+            carry = x >> 32;
+        }
+        digits.push(carry as u32);
+        BigNat {digits}.normalize()
+    }
+}
+
+impl ops::Add for BigNat {
+    type Output = BigNat;
+    fn add(self, other: BigNat) -> BigNat {&self + &other}
+}
+
+impl fmt::Display for BigNat {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        // Split into decimal chunks of 9 digits, least significant first.
+        let mut chunks = vec![];
+        let mut x = self.clone();
+        loop {
+            let (q, r) = x.div_rem_small(1_000_000_000);
+            chunks.push(r);
+            if q.is_zero() {break};
+            x = q;
+        }
+        write!(w, "{}", chunks.pop().unwrap())?;
+        for c in chunks.iter().rev() {write!(w, "{:09}", c)?;}
+        Ok(())
+    }
+}
+
+impl fmt::Debug for BigNat {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {fmt::Display::fmt(self, w)}
+}
@@ -5,7 +5,7 @@ pub trait Constrain<I> {
     type Lift;
 
     /// Override input constraint even when existential path does not exist.
-    fn i_force(&self, I) -> Self::Lift;
+    fn i_force(&self, i: I) -> Self::Lift;
 
     /// Constrains input but only if an existential path is supported for the constraint.
     fn i(&self, i: I) -> Self::Lift where Self::Lift: ExPath {self.i_force(i)}
@@ -13,9 +13,45 @@ pub trait Constrain<I> {
 
 impl<I> Constrain<I> for () {
     type Lift = ();
-    fn i_force(&self, _: I) -> () {()}
+    fn i_force(&self, _: I) -> Self::Lift {}
 }
 
+// `(g0, g1){(i0, i1)} <=> (g0{i0}, g1{i1})`
+impl<G0: Constrain<I0>, G1: Constrain<I1>, I0, I1> Constrain<(I0, I1)> for (G0, G1) {
+    type Lift = (G0::Lift, G1::Lift);
+    fn i_force(&self, i: (I0, I1)) -> Self::Lift {(self.0.i_force(i.0), self.1.i_force(i.1))}
+}
+
+// `(g0, g1){true_1} <=> (g0{true_1}, g1{true_1})`
+impl<G0: Constrain<()>, G1: Constrain<()>> Constrain<()> for (G0, G1) {
+    type Lift = (G0::Lift, G1::Lift);
+    fn i_force(&self, _: ()) -> Self::Lift {(self.0.i_force(()), self.1.i_force(()))}
+}
+
+// `(g0, g1){add_fits{i}} <=> (g0, g1){i}` for parity properties and constraints.
+// Every argument except `max` is used by some input where `a + b <= max`,
+// and parity properties do not tell `max` apart from `max - 2`.
+macro_rules! add_fits_impl {
+    ($g0:ident , $g1:ident) => {
+        add_fits_impl!{$g0, $g1, ()}
+        add_fits_impl!{$g0, $g1, (Even<T>, Even<T>)}
+        add_fits_impl!{$g0, $g1, (Even<T>, Odd<T>)}
+        add_fits_impl!{$g0, $g1, (Odd<T>, Even<T>)}
+        add_fits_impl!{$g0, $g1, (Odd<T>, Odd<T>)}
+    };
+    ($g0:ident , $g1:ident , $i:ty) => {
+        impl<T: Nat> Constrain<AddFits<T, $i>> for ($g0<T>, $g1<T>) {
+            type Lift = <($g0<T>, $g1<T>) as Constrain<$i>>::Lift;
+            fn i_force(&self, i: AddFits<T, $i>) -> Self::Lift {self.i_force(i.i)}
+        }
+    };
+}
+
+add_fits_impl!{Even, Even}
+add_fits_impl!{Even, Odd}
+add_fits_impl!{Odd, Even}
+add_fits_impl!{Odd, Odd}
+
 impl<Co: Clone, Tr: Clone, Fa: Clone, I, I2> Constrain<I> for If<Co, Tr, Fa, I2> {
     type Lift = If<Co, Tr, Fa, I>;
     fn i_force(&self, i: I) -> Self::Lift {
@@ -23,6 +59,183 @@ impl<Co: Clone, Tr: Clone, Fa: Clone, I, I2> Constrain<I> for If<Co, Tr, Fa, I2>
     }
 }
 
+impl<T: Clone, Co: Clone, Tr: Clone, Fa: Clone, I, I2> Constrain<I> for IfK<T, Co, Tr, Fa, I2> {
+    type Lift = IfK<T, Co, Tr, Fa, I>;
+    fn i_force(&self, i: I) -> Self::Lift {
+        IfK {k: self.k.clone(), co: self.co.clone(), tr: self.tr.clone(), fa: self.fa.clone(), i}
+    }
+}
+
+// `union(f){i} <=> union(f{i})`
+impl<F: Constrain<I>, I> Constrain<I> for Union<F> {
+    type Lift = Union<F::Lift>;
+    fn i_force(&self, i: I) -> Self::Lift {Union {f: self.f.i_force(i)}}
+}
+
+// `(g . f){i} <=> g . f{i}`
+impl<G: Clone, F: Constrain<I>, I> Constrain<I> for Compose<G, F> {
+    type Lift = Compose<G, F::Lift>;
+    fn i_force(&self, i: I) -> Self::Lift {
+        Compose {g: self.g.clone(), f: self.f.i_force(i)}
+    }
+}
+
+impl<A: Clone, B: Clone, I, I2> Constrain<I2> for Both<A, B, I> {
+    type Lift = Both<A, B, I2>;
+    fn i_force(&self, i: I2) -> Self::Lift {Both {a: self.a.clone(), b: self.b.clone(), i}}
+}
+
+impl<A: Clone, B: Clone, I, I2> Constrain<I2> for Either<A, B, I> {
+    type Lift = Either<A, B, I2>;
+    fn i_force(&self, i: I2) -> Self::Lift {Either {a: self.a.clone(), b: self.b.clone(), i}}
+}
+
+impl<A: Clone, I, I2> Constrain<I2> for Complement<A, I> {
+    type Lift = Complement<A, I2>;
+    fn i_force(&self, i: I2) -> Self::Lift {Complement {a: self.a.clone(), i}}
+}
+
+impl<T: Clone, I, I2> Constrain<I2> for RangeK<T, I> {
+    type Lift = RangeK<T, I2>;
+    fn i_force(&self, i: I2) -> Self::Lift {
+        RangeK {lo: self.lo.clone(), hi: self.hi.clone(), i}
+    }
+}
+
+impl<T: Clone, I, I2> Constrain<I2> for ModK<T, I> {
+    type Lift = ModK<T, I2>;
+    fn i_force(&self, i: I2) -> Self::Lift {
+        ModK {m: self.m.clone(), r: self.r.clone(), i}
+    }
+}
+
+/// Complement `¬a` of a predicate in the dictionary, e.g. `¬(>= k) <=> (< k)`.
+pub trait Negate {
+    type Lift;
+
+    fn negate(&self) -> Self::Lift;
+}
+
+impl Negate for Not {
+    type Lift = Id<bool>;
+    fn negate(&self) -> Self::Lift {Id::default()}
+}
+
+impl Negate for Id<bool> {
+    type Lift = Not;
+    fn negate(&self) -> Self::Lift {Not::default()}
+}
+
+impl<T> Negate for Even<T> {
+    type Lift = Odd<T>;
+    fn negate(&self) -> Self::Lift {Odd {t: PhantomData, i: ()}}
+}
+
+impl<T> Negate for Odd<T> {
+    type Lift = Even<T>;
+    fn negate(&self) -> Self::Lift {Even {t: PhantomData, i: ()}}
+}
+
+// `¬¬a <=> a`
+impl<A: Clone> Negate for Complement<A> {
+    type Lift = A;
+    fn negate(&self) -> Self::Lift {self.a.clone()}
+}
+
+// `¬(a ∧ b) <=> ¬a ∨ ¬b`
+impl<A: Negate, B: Negate> Negate for Both<A, B> {
+    type Lift = Either<A::Lift, B::Lift>;
+    fn negate(&self) -> Self::Lift {Either {a: self.a.negate(), b: self.b.negate(), i: ()}}
+}
+
+// `¬(a ∨ b) <=> ¬a ∧ ¬b`
+impl<A: Negate, B: Negate> Negate for Either<A, B> {
+    type Lift = Both<A::Lift, B::Lift>;
+    fn negate(&self) -> Self::Lift {Both {a: self.a.negate(), b: self.b.negate(), i: ()}}
+}
+
+macro_rules! negate_impl {
+    ($a:ident , $b:ident) => {
+        impl<T: Clone> Negate for $a<T> {
+            type Lift = $b<T>;
+            fn negate(&self) -> Self::Lift {$b {k: self.k.clone(), i: ()}}
+        }
+    };
+}
+
+negate_impl!{EqK, NeqK}
+negate_impl!{NeqK, EqK}
+negate_impl!{GeK, LtK}
+negate_impl!{LtK, GeK}
+negate_impl!{GtK, LeK}
+negate_impl!{LeK, GtK}
+
+/// Intersection `a ∧ i` of a constraint `i` with a predicate `a`.
+///
+/// This is `a` when the constraint is `true_1`, such that no `Both` is needed.
+pub trait Intersect<A> {
+    type Lift;
+
+    fn intersect(&self, a: A) -> Self::Lift;
+}
+
+impl<A> Intersect<A> for () {
+    type Lift = A;
+    fn intersect(&self, a: A) -> Self::Lift {a}
+}
+
+macro_rules! intersect_impl {
+    ($a:ident t) => {
+        impl<A, T, I: Clone> Intersect<A> for $a<T, I> {
+            type Lift = Both<A, $a<T, I>>;
+            fn intersect(&self, a: A) -> Self::Lift {
+                Both {a, b: $a {t: PhantomData, i: self.i.clone()}, i: ()}
+            }
+        }
+    };
+    ($a:ident k) => {
+        impl<A, T: Clone, I: Clone> Intersect<A> for $a<T, I> {
+            type Lift = Both<A, $a<T, I>>;
+            fn intersect(&self, a: A) -> Self::Lift {Both {a, b: self.clone(), i: ()}}
+        }
+    };
+    ($a:ident) => {
+        impl<A, I: Clone> Intersect<A> for $a<I> {
+            type Lift = Both<A, $a<I>>;
+            fn intersect(&self, a: A) -> Self::Lift {Both {a, b: self.clone(), i: ()}}
+        }
+    };
+}
+
+intersect_impl!{Not}
+intersect_impl!{Id t}
+intersect_impl!{False1 t}
+intersect_impl!{Even t}
+intersect_impl!{Odd t}
+intersect_impl!{EqK k}
+intersect_impl!{NeqK k}
+intersect_impl!{GeK k}
+intersect_impl!{LtK k}
+intersect_impl!{GtK k}
+intersect_impl!{LeK k}
+intersect_impl!{RangeK k}
+intersect_impl!{ModK k}
+
+impl<A, B: Clone, C: Clone, I: Clone> Intersect<A> for Both<B, C, I> {
+    type Lift = Both<A, Both<B, C, I>>;
+    fn intersect(&self, a: A) -> Self::Lift {Both {a, b: self.clone(), i: ()}}
+}
+
+impl<A, B: Clone, C: Clone, I: Clone> Intersect<A> for Either<B, C, I> {
+    type Lift = Both<A, Either<B, C, I>>;
+    fn intersect(&self, a: A) -> Self::Lift {Both {a, b: self.clone(), i: ()}}
+}
+
+impl<A, B: Clone, I: Clone> Intersect<A> for Complement<B, I> {
+    type Lift = Both<A, Complement<B, I>>;
+    fn intersect(&self, a: A) -> Self::Lift {Both {a, b: self.clone(), i: ()}}
+}
+
 macro_rules! con_impl {
     ($a:ident t) => {
         impl<T, I, I2> Constrain<I2> for $a<T, I> {
@@ -36,6 +249,12 @@ macro_rules! con_impl {
             fn i_force(&self, i: I2) -> Self::Lift {$a {k: self.k.clone(), i}}
         }
     };
+    ($a:ident c) => {
+        impl<const K: u64, I, I2> Constrain<I2> for $a<K, I> {
+            type Lift = $a<K, I2>;
+            fn i_force(&self, i: I2) -> Self::Lift {$a {i}}
+        }
+    };
     ($a:ident) => {
         impl<I, I2> Constrain<I2> for $a<I> {
             type Lift = $a<I2>;
@@ -61,6 +280,36 @@ con_impl!{Even t}
 con_impl!{Odd t}
 con_impl!{Add t}
 con_impl!{AddK k}
+con_impl!{WrappingAdd t}
+con_impl!{CheckedAdd t}
+con_impl!{SaturatingAdd t}
+con_impl!{AddFits t}
+con_impl!{Mul t}
+con_impl!{MulK k}
+con_impl!{Sub t}
+con_impl!{SubK k}
+con_impl!{Div t}
+con_impl!{DivK k}
+con_impl!{Rem t}
+con_impl!{RemK k}
+con_impl!{AddMod k}
 con_impl!{GeK k}
 con_impl!{LtK k}
+con_impl!{GtK k}
+con_impl!{LeK k}
+con_impl!{Ge t}
 con_impl!{EqK k}
+con_impl!{Neq t}
+con_impl!{NeqK k}
+con_impl!{Neg t}
+con_impl!{Abs t}
+con_impl!{IsNeg t}
+con_impl!{IsPos t}
+con_impl!{NonZero t}
+con_impl!{IsNan t}
+con_impl!{IsFinite t}
+con_impl!{SignBit t}
+con_impl!{EqC c}
+con_impl!{GeC c}
+con_impl!{LtC c}
+con_impl!{AddC c}
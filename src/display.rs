@@ -1,147 +1,300 @@
-//! This module uses unsafe code because `()` does not implement `Display`,
-//! which makes it impossible to hide it nicely.
+//! Displays objects as expressions, e.g. `and{not, true_1}`.
 
 use *;
 
 use std::fmt::{Display, Formatter, Result};
-use std::any::TypeId;
 
-/// Use same layout as `Display` to allow transmuting `&TrickDisplay` into `&Display`.
-/// This trait's methods will be called instead.
-pub trait TrickDisplay {
-    fn fmt(&self, fmt: &mut Formatter) -> Result;
+/// Renders an object, also when it is `()` which does not implement `Display`.
+pub trait Render {
+    fn render(&self, fmt: &mut Formatter) -> Result;
+
+    /// Returns `true` for `()`, which is left out as constraint.
+    fn is_true_1(&self) -> bool {false}
+
+    /// Returns `true` for `∧`, `∨` and `¬`, which are put in parentheses as operands.
+    fn is_set_op(&self) -> bool {false}
 }
 
-impl TrickDisplay for () {
-    fn fmt(&self, fmt: &mut Formatter) -> Result {write!(fmt, "true_1")}
+// Renders as `Display`.
+struct Show<'a, T: 'a + ?Sized>(&'a T);
+
+impl<'a, T: Render + ?Sized> Display for Show<'a, T> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {self.0.render(fmt)}
 }
 
-impl<T: Display, U: Display> TrickDisplay for (T, U) {
+// Renders as `Display`, in parentheses when it is an operand of `∧`, `∨` or `¬`.
+struct Operand<'a, T: 'a + ?Sized>(&'a T);
+
+impl<'a, T: Render + ?Sized> Display for Operand<'a, T> {
     fn fmt(&self, fmt: &mut Formatter) -> Result {
-        self.0.fmt(fmt)?;
+        if self.0.is_set_op() {
+            write!(fmt, "({})", Show(self.0))
+        } else {
+            self.0.render(fmt)
+        }
+    }
+}
+
+/// Renders an object to a string.
+pub fn render<T: Render + ?Sized>(a: &T) -> String {format!("{}", Show(a))}
+
+impl Render for () {
+    fn render(&self, fmt: &mut Formatter) -> Result {write!(fmt, "true_1")}
+    fn is_true_1(&self) -> bool {true}
+}
+
+impl<T: Render, U: Render> Render for (T, U) {
+    fn render(&self, fmt: &mut Formatter) -> Result {
+        self.0.render(fmt)?;
         write!(fmt, ", ")?;
-        self.1.fmt(fmt)
+        self.1.render(fmt)
     }
 }
 
-macro_rules! trick_impl {
+macro_rules! display_impl {
     ($a:ident, $b:tt) => {
-        impl<I: TrickDisplay + 'static> TrickDisplay for $a<I> {
-            fn fmt(&self, fmt: &mut Formatter) -> Result {
-                <Self as Display>::fmt(self, fmt)
-            }
-        }
-
-        impl<I: TrickDisplay + 'static> Display for $a<I> {
-            fn fmt(&self, fmt: &mut Formatter) -> Result {
-                if TypeId::of::<()>() == TypeId::of::<I>() {
-                    write!(fmt, $b)?;
+        impl<I: Render> Render for $a<I> {
+            fn render(&self, fmt: &mut Formatter) -> Result {
+                if self.i.is_true_1() {
+                    write!(fmt, $b)
                 } else {
-                    use std::mem::transmute;
-                    let i = unsafe {transmute::<&TrickDisplay, &Display>(&self.i as &TrickDisplay)};
-                    write!(fmt, concat!($b, "{{{}}}"), i)?;
+                    write!(fmt, concat!($b, "{{{}}}"), Show(&self.i))
                 }
-                Ok(())
             }
         }
+
+        impl<I: Render> Display for $a<I> {
+            fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+        }
     };
     ($a:ident t, $b:tt) => {
-        impl<T, I: TrickDisplay + 'static> TrickDisplay for $a<T, I> {
-            fn fmt(&self, fmt: &mut Formatter) -> Result {
-                <Self as Display>::fmt(self, fmt)
-            }
-        }
-
-        impl<T, I: TrickDisplay + 'static> Display for $a<T, I> {
-            fn fmt(&self, fmt: &mut Formatter) -> Result {
-                if TypeId::of::<()>() == TypeId::of::<I>() {
-                    write!(fmt, $b)?;
+        impl<T, I: Render> Render for $a<T, I> {
+            fn render(&self, fmt: &mut Formatter) -> Result {
+                if self.i.is_true_1() {
+                    write!(fmt, $b)
                 } else {
-                    use std::mem::transmute;
-                    let i = unsafe {transmute::<&TrickDisplay, &Display>(&self.i as &TrickDisplay)};
-                    write!(fmt, concat!($b, "{{{}}}"), i)?;
+                    write!(fmt, concat!($b, "{{{}}}"), Show(&self.i))
                 }
-                Ok(())
             }
         }
+
+        impl<T, I: Render> Display for $a<T, I> {
+            fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+        }
     };
     ($a:ident k, $b:tt) => {
-        impl<T: Display, I: TrickDisplay + 'static> TrickDisplay for $a<T, I> {
-            fn fmt(&self, fmt: &mut Formatter) -> Result {
-                <Self as Display>::fmt(self, fmt)
-            }
-        }
-
-        impl<T: Display, I: TrickDisplay + 'static> Display for $a<T, I> {
-            fn fmt(&self, fmt: &mut Formatter) -> Result {
-                if TypeId::of::<()>() == TypeId::of::<I>() {
+        impl<T: Display, I: Render> Render for $a<T, I> {
+            fn render(&self, fmt: &mut Formatter) -> Result {
+                if self.i.is_true_1() {
                     write!(fmt, $b, self.k)
                 } else {
-                    use std::mem::transmute;
-                    let i = unsafe {transmute::<&TrickDisplay, &Display>(&self.i as &TrickDisplay)};
-                    write!(fmt, concat!($b, "{{{}}}"), self.k, i)
+                    write!(fmt, concat!($b, "{{{}}}"), self.k, Show(&self.i))
                 }
             }
         }
+
+        impl<T: Display, I: Render> Display for $a<T, I> {
+            fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+        }
     };
 }
 
-impl<T: Display, Co: TrickDisplay, Tr: TrickDisplay, Fa: TrickDisplay, I: 'static + TrickDisplay>
-Display for IfK<T, Co, Tr, Fa, I> {
-    fn fmt(&self, fmt: &mut Formatter) -> Result {
-        use std::mem::transmute;
-        let co = unsafe {transmute::<&TrickDisplay, &Display>(&self.co)};
-        let tr = unsafe {transmute::<&TrickDisplay, &Display>(&self.tr)};
-        let fa = unsafe {transmute::<&TrickDisplay, &Display>(&self.fa)};
-        if TypeId::of::<()>() == TypeId::of::<I>() {
+impl<T: Display, Co: Render, Tr: Render, Fa: Render, I: Render>
+Render for IfK<T, Co, Tr, Fa, I> {
+    fn render(&self, fmt: &mut Formatter) -> Result {
+        let (co, tr, fa) = (Show(&self.co), Show(&self.tr), Show(&self.fa));
+        if self.i.is_true_1() {
             write!(fmt, "if {}({}) {{{}}} else {{{}}}", co, self.k, tr, fa)
         } else {
-            let i = unsafe {transmute::<&TrickDisplay, &Display>(&self.i)};
+            let i = Show(&self.i);
             write!(fmt, "if {}({}) {{{}{{{}}}}} else {{{}{{{}}}}}", co, self.k, tr, i, fa, i)
         }
     }
 }
 
-impl<Co: TrickDisplay, Tr: TrickDisplay, Fa: TrickDisplay, I: 'static + TrickDisplay>
-TrickDisplay for If<Co, Tr, Fa, I> {
-    fn fmt(&self, fmt: &mut Formatter) -> Result {
-        <Self as Display>::fmt(self, fmt)
+impl<T: Display, Co: Render, Tr: Render, Fa: Render, I: Render>
+Display for IfK<T, Co, Tr, Fa, I> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+}
+
+impl<Co: Render, Tr: Render, Fa: Render, I: Render>
+Render for If<Co, Tr, Fa, I> {
+    fn render(&self, fmt: &mut Formatter) -> Result {
+        let (co, tr, fa) = (Show(&self.co), Show(&self.tr), Show(&self.fa));
+        if self.i.is_true_1() {
+            write!(fmt, "if({}, {}, {})", co, tr, fa)
+        } else {
+            write!(fmt, "if({}, {}, {}){{{}}}", co, tr, fa, Show(&self.i))
+        }
     }
 }
 
-impl<Co: TrickDisplay, Tr: TrickDisplay, Fa: TrickDisplay, I: 'static + TrickDisplay>
+impl<Co: Render, Tr: Render, Fa: Render, I: Render>
 Display for If<Co, Tr, Fa, I> {
-    fn fmt(&self, fmt: &mut Formatter) -> Result {
-        use std::mem::transmute;
-        let co = unsafe {transmute::<&TrickDisplay, &Display>(&self.co)};
-        let tr = unsafe {transmute::<&TrickDisplay, &Display>(&self.tr)};
-        let fa = unsafe {transmute::<&TrickDisplay, &Display>(&self.fa)};
-        if TypeId::of::<()>() == TypeId::of::<I>() {
-            write!(fmt, "if({}, {}, {})", co, tr, fa)
+    fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+}
+
+impl<A: Render, B: Render, I: Render> Render for Both<A, B, I> {
+    fn render(&self, fmt: &mut Formatter) -> Result {
+        if self.i.is_true_1() {
+            write!(fmt, "{} ∧ {}", Operand(&self.a), Operand(&self.b))
+        } else {
+            write!(fmt, "({} ∧ {}){{{}}}", Operand(&self.a), Operand(&self.b), Show(&self.i))
+        }
+    }
+
+    fn is_set_op(&self) -> bool {self.i.is_true_1()}
+}
+
+impl<A: Render, B: Render, I: Render> Display for Both<A, B, I> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+}
+
+impl<A: Render, B: Render, I: Render> Render for Either<A, B, I> {
+    fn render(&self, fmt: &mut Formatter) -> Result {
+        if self.i.is_true_1() {
+            write!(fmt, "{} ∨ {}", Operand(&self.a), Operand(&self.b))
+        } else {
+            write!(fmt, "({} ∨ {}){{{}}}", Operand(&self.a), Operand(&self.b), Show(&self.i))
+        }
+    }
+
+    fn is_set_op(&self) -> bool {self.i.is_true_1()}
+}
+
+impl<A: Render, B: Render, I: Render> Display for Either<A, B, I> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+}
+
+impl<A: Render, I: Render> Render for Complement<A, I> {
+    fn render(&self, fmt: &mut Formatter) -> Result {
+        if self.i.is_true_1() {
+            write!(fmt, "¬{}", Operand(&self.a))
         } else {
-            let i = unsafe {transmute::<&TrickDisplay, &Display>(&self.i)};
-            write!(fmt, "if({}, {}, {}){{{}}}", co, tr, fa, i)
+            write!(fmt, "(¬{}){{{}}}", Operand(&self.a), Show(&self.i))
         }
     }
+
+    fn is_set_op(&self) -> bool {self.i.is_true_1()}
+}
+
+impl<A: Render, I: Render> Display for Complement<A, I> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+}
+
+impl<F: Render> Render for Union<F> {
+    fn render(&self, fmt: &mut Formatter) -> Result {
+        write!(fmt, "union({})", Show(&self.f))
+    }
+}
+
+impl<F: Render> Display for Union<F> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+}
+
+impl<G: Render, F: Render> Render for Compose<G, F> {
+    fn render(&self, fmt: &mut Formatter) -> Result {
+        write!(fmt, "{} . {}", Show(&self.g), Show(&self.f))
+    }
+}
+
+impl<G: Render, F: Render> Display for Compose<G, F> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+}
+
+impl<T: Display, I: Render> Render for RangeK<T, I> {
+    fn render(&self, fmt: &mut Formatter) -> Result {
+        if self.i.is_true_1() {
+            write!(fmt, "[{}, {})", self.lo, self.hi)
+        } else {
+            write!(fmt, "[{}, {}){{{}}}", self.lo, self.hi, Show(&self.i))
+        }
+    }
+}
+
+impl<T: Display, I: Render> Display for RangeK<T, I> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+}
+
+impl<T: Display, I: Render> Render for ModK<T, I> {
+    fn render(&self, fmt: &mut Formatter) -> Result {
+        if self.i.is_true_1() {
+            write!(fmt, "(% {} = {})", self.m, self.r)
+        } else {
+            write!(fmt, "(% {} = {}){{{}}}", self.m, self.r, Show(&self.i))
+        }
+    }
+}
+
+impl<T: Display, I: Render> Display for ModK<T, I> {
+    fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+}
+
+display_impl!{LtK k, "(< {})"}
+display_impl!{EqK k, "(= {})"}
+display_impl!{NeqK k, "(!= {})"}
+display_impl!{GeK k, "(>= {})"}
+display_impl!{GtK k, "(> {})"}
+display_impl!{LeK k, "(<= {})"}
+display_impl!{AddK k, "add({})"}
+display_impl!{MulK k, "mul({})"}
+display_impl!{SubK k, "sub({})"}
+display_impl!{DivK k, "div({})"}
+display_impl!{RemK k, "rem({})"}
+display_impl!{AddMod k, "add_mod({})"}
+display_impl!{Even t, "even"}
+display_impl!{Odd t, "odd"}
+display_impl!{Neg t, "neg"}
+display_impl!{Abs t, "abs"}
+display_impl!{IsNeg t, "is_neg"}
+display_impl!{IsPos t, "is_pos"}
+display_impl!{NonZero t, "non_zero"}
+display_impl!{IsNan t, "is_nan"}
+display_impl!{IsFinite t, "is_finite"}
+display_impl!{SignBit t, "sign_bit"}
+display_impl!{False1 t, "false_1"}
+display_impl!{Eq t, "eq"}
+display_impl!{Neq t, "neq"}
+display_impl!{Add t, "add"}
+display_impl!{WrappingAdd t, "wrapping_add"}
+display_impl!{CheckedAdd t, "checked_add"}
+display_impl!{SaturatingAdd t, "saturating_add"}
+display_impl!{AddFits t, "add_fits"}
+display_impl!{Mul t, "mul"}
+display_impl!{Sub t, "sub"}
+display_impl!{Div t, "div"}
+display_impl!{Rem t, "rem"}
+display_impl!{Ge t, "ge"}
+display_impl!{Id t, "id"}
+display_impl!{Not, "not"}
+display_impl!{And, "and"}
+display_impl!{Or, "or"}
+display_impl!{Xor, "xor"}
+display_impl!{Nor, "nor"}
+display_impl!{Nand, "nand"}
+display_impl!{Exc, "exc"}
+display_impl!{Nrexc, "nrexc"}
+display_impl!{Rexc, "rexc"}
+display_impl!{Nexc, "nexc"}
+
+// Constants in the type are displayed as runtime constants.
+macro_rules! display_const_impl {
+    ($a:ident => $b:ident) => {
+        impl<const K: u64, I: Dynamic> Render for $a<K, I>
+            where $b<u64, I::Lift>: Render
+        {
+            fn render(&self, fmt: &mut Formatter) -> Result {self.dynamic().render(fmt)}
+        }
+
+        impl<const K: u64, I: Dynamic> Display for $a<K, I>
+            where $b<u64, I::Lift>: Render
+        {
+            fn fmt(&self, fmt: &mut Formatter) -> Result {self.render(fmt)}
+        }
+    };
 }
 
-trick_impl!{LtK k, "(< {})"}
-trick_impl!{EqK k, "(= {})"}
-trick_impl!{GeK k, "(>= {})"}
-trick_impl!{AddK k, "add({})"}
-trick_impl!{Even t, "even"}
-trick_impl!{Odd t, "odd"}
-trick_impl!{False1 t, "false_1"}
-trick_impl!{Eq t, "eq"}
-trick_impl!{Add t, "add"}
-trick_impl!{Id t, "id"}
-trick_impl!{Not, "not"}
-trick_impl!{And, "and"}
-trick_impl!{Or, "or"}
-trick_impl!{Xor, "xor"}
-trick_impl!{Nor, "nor"}
-trick_impl!{Nand, "nand"}
-trick_impl!{Exc, "exc"}
-trick_impl!{Nrexc, "nrexc"}
-trick_impl!{Rexc, "rexc"}
-trick_impl!{Nexc, "nexc"}
+display_const_impl!{EqC => EqK}
+display_const_impl!{GeC => GeK}
+display_const_impl!{LtC => LtK}
+display_const_impl!{AddC => AddK}
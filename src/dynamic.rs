@@ -0,0 +1,45 @@
+//! Conversion of compile-time constants to runtime constants.
+
+use *;
+
+/// Replaces constants in the type of a constrained function with runtime constants.
+///
+/// This is used to reuse the rules of e.g. `EqK` for `EqC`
+/// when the existential path depends on the value of the constant.
+pub trait Dynamic {
+    type Lift;
+
+    fn dynamic(&self) -> Self::Lift;
+}
+
+impl Dynamic for () {
+    type Lift = ();
+    fn dynamic(&self) -> Self::Lift {}
+}
+
+impl<A: Dynamic, B: Dynamic> Dynamic for (A, B) {
+    type Lift = (A::Lift, B::Lift);
+    fn dynamic(&self) -> Self::Lift {(self.0.dynamic(), self.1.dynamic())}
+}
+
+macro_rules! dynamic_impl {
+    ($a:ident t) => {
+        impl<T, I: Dynamic> Dynamic for $a<T, I> {
+            type Lift = $a<T, I::Lift>;
+            fn dynamic(&self) -> Self::Lift {$a {t: PhantomData, i: self.i.dynamic()}}
+        }
+    };
+    ($a:ident c => $b:ident) => {
+        impl<const K: u64, I: Dynamic> Dynamic for $a<K, I> {
+            type Lift = $b<u64, I::Lift>;
+            fn dynamic(&self) -> Self::Lift {$b {k: K, i: self.i.dynamic()}}
+        }
+    };
+}
+
+dynamic_impl!{Even t}
+dynamic_impl!{Odd t}
+dynamic_impl!{EqC c => EqK}
+dynamic_impl!{GeC c => GeK}
+dynamic_impl!{LtC c => LtK}
+dynamic_impl!{AddC c => AddK}
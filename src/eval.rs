@@ -0,0 +1,784 @@
+use *;
+
+/// Evaluates a constrained function.
+pub trait Eval {
+    type Input;
+    type Output;
+
+    /// Returns `None` if the input does not satisfy the constraint.
+    fn eval(&self, x: Self::Input) -> Option<Self::Output>;
+}
+
+/// Checks whether some input satisfies a constraint.
+///
+/// This is implemented for `()` (`true_1`) and tuples separately from `Eval`,
+/// because they are used as constraints for any input type.
+pub trait Check<X> {
+    fn check(&self, x: &X) -> bool;
+}
+
+impl<X> Check<X> for () {
+    fn check(&self, _: &X) -> bool {true}
+}
+
+impl<X, Y, A: Check<X>, B: Check<Y>> Check<(X, Y)> for (A, B) {
+    fn check(&self, x: &(X, Y)) -> bool {
+        self.0.check(&x.0) && self.1.check(&x.1)
+    }
+}
+
+// A predicate used as constraint of a binary function applies to both arguments.
+macro_rules! check_impl {
+    ($a:ident t) => {
+        impl<T: Clone, I> Check<T> for $a<T, I>
+            where $a<T, I>: Eval<Input = T, Output = bool>
+        {
+            fn check(&self, x: &T) -> bool {self.eval(x.clone()) == Some(true)}
+        }
+
+        impl<T: Clone, I> Check<(T, T)> for $a<T, I>
+            where $a<T, I>: Eval<Input = T, Output = bool>
+        {
+            fn check(&self, x: &(T, T)) -> bool {self.check(&x.0) && self.check(&x.1)}
+        }
+    };
+    ($a:ident) => {
+        impl<I> Check<bool> for $a<I>
+            where $a<I>: Eval<Input = bool, Output = bool>
+        {
+            fn check(&self, x: &bool) -> bool {self.eval(*x) == Some(true)}
+        }
+
+        impl<I> Check<(bool, bool)> for $a<I>
+            where $a<I>: Eval<Input = bool, Output = bool>
+        {
+            fn check(&self, x: &(bool, bool)) -> bool {self.check(&x.0) && self.check(&x.1)}
+        }
+    };
+}
+
+check_impl!{False1 t}
+check_impl!{Id t}
+check_impl!{Not}
+check_impl!{EqK t}
+check_impl!{NeqK t}
+check_impl!{Even t}
+check_impl!{Odd t}
+check_impl!{ModK t}
+check_impl!{IsNeg t}
+check_impl!{IsPos t}
+check_impl!{NonZero t}
+check_impl!{IsNan t}
+check_impl!{IsFinite t}
+check_impl!{SignBit t}
+check_impl!{GeK t}
+check_impl!{LtK t}
+check_impl!{GtK t}
+check_impl!{LeK t}
+check_impl!{RangeK t}
+
+impl<X: Clone, Co, Tr, Fa, I> Check<X> for If<Co, Tr, Fa, I>
+    where If<Co, Tr, Fa, I>: Eval<Input = X, Output = bool>
+{
+    fn check(&self, x: &X) -> bool {self.eval(x.clone()) == Some(true)}
+}
+
+impl<T: Clone, I> Check<(T, T)> for Ge<T, I>
+    where Ge<T, I>: Eval<Input = (T, T), Output = bool>
+{
+    fn check(&self, x: &(T, T)) -> bool {self.eval(x.clone()) == Some(true)}
+}
+
+impl<T: Clone, I> Check<(T, T)> for AddFits<T, I>
+    where AddFits<T, I>: Eval<Input = (T, T), Output = bool>
+{
+    fn check(&self, x: &(T, T)) -> bool {self.eval(x.clone()) == Some(true)}
+}
+
+// Combinators are checked for each argument of a binary function,
+// which is why they are implemented per input type instead of generically.
+macro_rules! combinator_check_impl {
+    ($($t:ty),*) => {$(
+        impl<A: Check<$t>, B: Check<$t>, I: Check<$t>> Check<$t> for Both<A, B, I> {
+            fn check(&self, x: &$t) -> bool {self.i.check(x) && self.a.check(x) && self.b.check(x)}
+        }
+
+        impl<A: Check<$t>, B: Check<$t>, I: Check<$t>> Check<($t, $t)> for Both<A, B, I> {
+            fn check(&self, x: &($t, $t)) -> bool {self.check(&x.0) && self.check(&x.1)}
+        }
+
+        impl<A: Check<$t>, B: Check<$t>, I: Check<$t>> Check<$t> for Either<A, B, I> {
+            fn check(&self, x: &$t) -> bool {self.i.check(x) && (self.a.check(x) || self.b.check(x))}
+        }
+
+        impl<A: Check<$t>, B: Check<$t>, I: Check<$t>> Check<($t, $t)> for Either<A, B, I> {
+            fn check(&self, x: &($t, $t)) -> bool {self.check(&x.0) && self.check(&x.1)}
+        }
+
+        impl<A: Check<$t>, I: Check<$t>> Check<$t> for Complement<A, I> {
+            fn check(&self, x: &$t) -> bool {self.i.check(x) && !self.a.check(x)}
+        }
+
+        impl<A: Check<$t>, I: Check<$t>> Check<($t, $t)> for Complement<A, I> {
+            fn check(&self, x: &($t, $t)) -> bool {self.check(&x.0) && self.check(&x.1)}
+        }
+    )*};
+}
+
+combinator_check_impl!{bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, f32, f64, BigNat}
+
+impl<X: Clone, G, F> Check<X> for Compose<G, F>
+    where Compose<G, F>: Eval<Input = X, Output = bool>
+{
+    fn check(&self, x: &X) -> bool {self.eval(x.clone()) == Some(true)}
+}
+
+impl<X: Clone, T, Co, Tr, Fa, I> Check<X> for IfK<T, Co, Tr, Fa, I>
+    where IfK<T, Co, Tr, Fa, I>: Eval<Input = X, Output = bool>
+{
+    fn check(&self, x: &X) -> bool {self.eval(x.clone()) == Some(true)}
+}
+
+impl<T, I: Check<T>> Eval for False1<T, I> {
+    type Input = T;
+    type Output = bool;
+    fn eval(&self, x: T) -> Option<bool> {
+        if self.i.check(&x) {Some(false)} else {None}
+    }
+}
+
+impl<T, I: Check<T>> Eval for Id<T, I> {
+    type Input = T;
+    type Output = T;
+    fn eval(&self, x: T) -> Option<T> {
+        if self.i.check(&x) {Some(x)} else {None}
+    }
+}
+
+impl<I: Check<bool>> Eval for Not<I> {
+    type Input = bool;
+    type Output = bool;
+    fn eval(&self, x: bool) -> Option<bool> {
+        if self.i.check(&x) {Some(!x)} else {None}
+    }
+}
+
+impl<T: PartialEq, I: Check<(T, T)>> Eval for Eq<T, I> {
+    type Input = (T, T);
+    type Output = bool;
+    fn eval(&self, x: (T, T)) -> Option<bool> {
+        if self.i.check(&x) {Some(x.0 == x.1)} else {None}
+    }
+}
+
+impl<T: PartialEq, I: Check<T>> Eval for EqK<T, I> {
+    type Input = T;
+    type Output = bool;
+    fn eval(&self, x: T) -> Option<bool> {
+        if self.i.check(&x) {Some(x == self.k)} else {None}
+    }
+}
+
+impl<T: PartialEq, I: Check<(T, T)>> Eval for Neq<T, I> {
+    type Input = (T, T);
+    type Output = bool;
+    fn eval(&self, x: (T, T)) -> Option<bool> {
+        if self.i.check(&x) {Some(x.0 != x.1)} else {None}
+    }
+}
+
+impl<T: PartialEq, I: Check<T>> Eval for NeqK<T, I> {
+    type Input = T;
+    type Output = bool;
+    fn eval(&self, x: T) -> Option<bool> {
+        if self.i.check(&x) {Some(x != self.k)} else {None}
+    }
+}
+
+impl<T: PartialOrd, I: Check<T>> Eval for GeK<T, I> {
+    type Input = T;
+    type Output = bool;
+    fn eval(&self, x: T) -> Option<bool> {
+        if self.i.check(&x) {Some(x >= self.k)} else {None}
+    }
+}
+
+impl<T: PartialOrd, I: Check<T>> Eval for GtK<T, I> {
+    type Input = T;
+    type Output = bool;
+    fn eval(&self, x: T) -> Option<bool> {
+        if self.i.check(&x) {Some(x > self.k)} else {None}
+    }
+}
+
+impl<T: PartialOrd, I: Check<T>> Eval for LeK<T, I> {
+    type Input = T;
+    type Output = bool;
+    fn eval(&self, x: T) -> Option<bool> {
+        if self.i.check(&x) {Some(x <= self.k)} else {None}
+    }
+}
+
+impl<T: PartialOrd, I: Check<T>> Eval for RangeK<T, I> {
+    type Input = T;
+    type Output = bool;
+    fn eval(&self, x: T) -> Option<bool> {
+        if self.i.check(&x) {Some(self.lo <= x && x < self.hi)} else {None}
+    }
+}
+
+impl<T: PartialOrd, I: Check<(T, T)>> Eval for Ge<T, I> {
+    type Input = (T, T);
+    type Output = bool;
+    fn eval(&self, x: (T, T)) -> Option<bool> {
+        if self.i.check(&x) {Some(x.0 >= x.1)} else {None}
+    }
+}
+
+impl<T: PartialOrd, I: Check<T>> Eval for LtK<T, I> {
+    type Input = T;
+    type Output = bool;
+    fn eval(&self, x: T) -> Option<bool> {
+        if self.i.check(&x) {Some(x < self.k)} else {None}
+    }
+}
+
+macro_rules! eval_impl {
+    ($a:ident , | $x:ident , $y:ident | $e:expr) => {
+        impl<I: Check<(bool, bool)>> Eval for $a<I> {
+            type Input = (bool, bool);
+            type Output = bool;
+            fn eval(&self, x: (bool, bool)) -> Option<bool> {
+                if self.i.check(&x) {
+                    let ($x, $y) = x;
+                    Some($e)
+                } else {None}
+            }
+        }
+    };
+}
+
+eval_impl!{And, |a, b| a && b}
+eval_impl!{Or, |a, b| a || b}
+eval_impl!{Xor, |a, b| a ^ b}
+eval_impl!{Nand, |a, b| !(a && b)}
+eval_impl!{Nor, |a, b| !(a || b)}
+eval_impl!{Exc, |a, b| a && !b}
+eval_impl!{Nrexc, |a, b| a || !b}
+eval_impl!{Rexc, |a, b| !a && b}
+eval_impl!{Nexc, |a, b| !a || b}
+
+// Addition and multiplication of natural numbers is not defined when the result overflows.
+// Subtraction is not defined when the result is negative.
+// Division and remainder are not defined when dividing by zero.
+impl<T: Nat, I: Check<T>> Eval for Even<T, I> {
+    type Input = T;
+    type Output = bool;
+    fn eval(&self, x: T) -> Option<bool> {
+        if self.i.check(&x) {Some(x % T::from(2) == T::ZERO)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<T>> Eval for Odd<T, I> {
+    type Input = T;
+    type Output = bool;
+    fn eval(&self, x: T) -> Option<bool> {
+        if self.i.check(&x) {Some(x % T::from(2) == T::ONE)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<(T, T)>> Eval for Add<T, I> {
+    type Input = (T, T);
+    type Output = T;
+    fn eval(&self, x: (T, T)) -> Option<T> {
+        if self.i.check(&x) {x.0.checked_add(x.1)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<T>> Eval for AddK<T, I> {
+    type Input = T;
+    type Output = T;
+    fn eval(&self, x: T) -> Option<T> {
+        if self.i.check(&x) {x.checked_add(self.k)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<(T, T)>> Eval for WrappingAdd<T, I> {
+    type Input = (T, T);
+    type Output = T;
+    fn eval(&self, x: (T, T)) -> Option<T> {
+        if self.i.check(&x) {Some(x.0.wrapping_add(x.1))} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<(T, T)>> Eval for CheckedAdd<T, I> {
+    type Input = (T, T);
+    type Output = T;
+    fn eval(&self, x: (T, T)) -> Option<T> {
+        if self.i.check(&x) {x.0.checked_add(x.1)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<(T, T)>> Eval for SaturatingAdd<T, I> {
+    type Input = (T, T);
+    type Output = T;
+    fn eval(&self, x: (T, T)) -> Option<T> {
+        if self.i.check(&x) {Some(x.0.saturating_add(x.1))} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<(T, T)>> Eval for AddFits<T, I> {
+    type Input = (T, T);
+    type Output = bool;
+    fn eval(&self, x: (T, T)) -> Option<bool> {
+        if self.i.check(&x) {Some(x.0.checked_add(x.1).is_some())} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<(T, T)>> Eval for Mul<T, I> {
+    type Input = (T, T);
+    type Output = T;
+    fn eval(&self, x: (T, T)) -> Option<T> {
+        if self.i.check(&x) {x.0.checked_mul(x.1)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<T>> Eval for MulK<T, I> {
+    type Input = T;
+    type Output = T;
+    fn eval(&self, x: T) -> Option<T> {
+        if self.i.check(&x) {x.checked_mul(self.k)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<(T, T)>> Eval for Sub<T, I> {
+    type Input = (T, T);
+    type Output = T;
+    fn eval(&self, x: (T, T)) -> Option<T> {
+        if self.i.check(&x) {x.0.checked_sub(x.1)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<T>> Eval for SubK<T, I> {
+    type Input = T;
+    type Output = T;
+    fn eval(&self, x: T) -> Option<T> {
+        if self.i.check(&x) {x.checked_sub(self.k)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<(T, T)>> Eval for Div<T, I> {
+    type Input = (T, T);
+    type Output = T;
+    fn eval(&self, x: (T, T)) -> Option<T> {
+        if self.i.check(&x) {x.0.checked_div(x.1)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<T>> Eval for DivK<T, I> {
+    type Input = T;
+    type Output = T;
+    fn eval(&self, x: T) -> Option<T> {
+        if self.i.check(&x) {x.checked_div(self.k)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<(T, T)>> Eval for Rem<T, I> {
+    type Input = (T, T);
+    type Output = T;
+    fn eval(&self, x: (T, T)) -> Option<T> {
+        if self.i.check(&x) {x.0.checked_rem(x.1)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<T>> Eval for RemK<T, I> {
+    type Input = T;
+    type Output = T;
+    fn eval(&self, x: T) -> Option<T> {
+        if self.i.check(&x) {x.checked_rem(self.k)} else {None}
+    }
+}
+
+impl<T: Nat, I: Check<(T, T)>> Eval for AddMod<T, I> {
+    type Input = (T, T);
+    type Output = T;
+    fn eval(&self, x: (T, T)) -> Option<T> {
+        if self.i.check(&x) && self.k != T::ZERO {
+            let (a, b) = (x.0 % self.k, x.1 % self.k);
+            // Subtract the complement of `b` to avoid overflow.
+            Some(if a >= self.k - b {a - (self.k - b)} else {a + b})
+        } else {None}
+    }
+}
+
+impl<T: Nat, I: Check<T>> Eval for ModK<T, I> {
+    type Input = T;
+    type Output = bool;
+    fn eval(&self, x: T) -> Option<bool> {
+        if self.i.check(&x) {Some(x.checked_rem(self.m).unwrap_or(x) == self.r)} else {None}
+    }
+}
+
+// Addition of arbitrary-precision natural numbers never overflows.
+impl<I: Check<BigNat>> Eval for Even<BigNat, I> {
+    type Input = BigNat;
+    type Output = bool;
+    fn eval(&self, x: BigNat) -> Option<bool> {
+        if self.i.check(&x) {Some(x.is_even())} else {None}
+    }
+}
+
+impl<I: Check<BigNat>> Eval for Odd<BigNat, I> {
+    type Input = BigNat;
+    type Output = bool;
+    fn eval(&self, x: BigNat) -> Option<bool> {
+        if self.i.check(&x) {Some(!x.is_even())} else {None}
+    }
+}
+
+impl<I: Check<(BigNat, BigNat)>> Eval for Add<BigNat, I> {
+    type Input = (BigNat, BigNat);
+    type Output = BigNat;
+    fn eval(&self, x: (BigNat, BigNat)) -> Option<BigNat> {
+        if self.i.check(&x) {Some(x.0 + x.1)} else {None}
+    }
+}
+
+impl<I: Check<BigNat>> Eval for AddK<BigNat, I> {
+    type Input = BigNat;
+    type Output = BigNat;
+    fn eval(&self, x: BigNat) -> Option<BigNat> {
+        if self.i.check(&x) {Some(&x + &self.k)} else {None}
+    }
+}
+
+// Signed integers have the same overflow rules as natural numbers,
+// plus negation and absolute value are not defined for `min`.
+macro_rules! int_impl {
+    ($t:ident) => {
+        impl<I: Check<$t>> Eval for Even<$t, I> {
+            type Input = $t;
+            type Output = bool;
+            fn eval(&self, x: $t) -> Option<bool> {
+                if self.i.check(&x) {Some(x % 2 == 0)} else {None}
+            }
+        }
+
+        // The remainder of a negative odd number is `-1`.
+        impl<I: Check<$t>> Eval for Odd<$t, I> {
+            type Input = $t;
+            type Output = bool;
+            fn eval(&self, x: $t) -> Option<bool> {
+                if self.i.check(&x) {Some(x % 2 != 0)} else {None}
+            }
+        }
+
+        impl<I: Check<($t, $t)>> Eval for Add<$t, I> {
+            type Input = ($t, $t);
+            type Output = $t;
+            fn eval(&self, x: ($t, $t)) -> Option<$t> {
+                if self.i.check(&x) {x.0.checked_add(x.1)} else {None}
+            }
+        }
+
+        impl<I: Check<($t, $t)>> Eval for AddFits<$t, I> {
+            type Input = ($t, $t);
+            type Output = bool;
+            fn eval(&self, x: ($t, $t)) -> Option<bool> {
+                if self.i.check(&x) {Some(x.0.checked_add(x.1).is_some())} else {None}
+            }
+        }
+
+        impl<I: Check<$t>> Eval for AddK<$t, I> {
+            type Input = $t;
+            type Output = $t;
+            fn eval(&self, x: $t) -> Option<$t> {
+                if self.i.check(&x) {x.checked_add(self.k)} else {None}
+            }
+        }
+
+        impl<I: Check<($t, $t)>> Eval for Mul<$t, I> {
+            type Input = ($t, $t);
+            type Output = $t;
+            fn eval(&self, x: ($t, $t)) -> Option<$t> {
+                if self.i.check(&x) {x.0.checked_mul(x.1)} else {None}
+            }
+        }
+
+        impl<I: Check<$t>> Eval for MulK<$t, I> {
+            type Input = $t;
+            type Output = $t;
+            fn eval(&self, x: $t) -> Option<$t> {
+                if self.i.check(&x) {x.checked_mul(self.k)} else {None}
+            }
+        }
+
+        impl<I: Check<$t>> Eval for Neg<$t, I> {
+            type Input = $t;
+            type Output = $t;
+            fn eval(&self, x: $t) -> Option<$t> {
+                if self.i.check(&x) {x.checked_neg()} else {None}
+            }
+        }
+
+        impl<I: Check<$t>> Eval for Abs<$t, I> {
+            type Input = $t;
+            type Output = $t;
+            fn eval(&self, x: $t) -> Option<$t> {
+                if self.i.check(&x) {x.checked_abs()} else {None}
+            }
+        }
+
+        impl<I: Check<$t>> Eval for IsNeg<$t, I> {
+            type Input = $t;
+            type Output = bool;
+            fn eval(&self, x: $t) -> Option<bool> {
+                if self.i.check(&x) {Some(x < 0)} else {None}
+            }
+        }
+
+        impl<I: Check<$t>> Eval for IsPos<$t, I> {
+            type Input = $t;
+            type Output = bool;
+            fn eval(&self, x: $t) -> Option<bool> {
+                if self.i.check(&x) {Some(x > 0)} else {None}
+            }
+        }
+
+        impl<I: Check<$t>> Eval for NonZero<$t, I> {
+            type Input = $t;
+            type Output = bool;
+            fn eval(&self, x: $t) -> Option<bool> {
+                if self.i.check(&x) {Some(x != 0)} else {None}
+            }
+        }
+    };
+}
+
+int_impl!{i8}
+int_impl!{i16}
+int_impl!{i32}
+int_impl!{i64}
+
+// Floats follow IEEE 754, so arithmetic is defined for all inputs.
+// Overflow gives infinity and invalid operations give NaN.
+macro_rules! float_impl {
+    ($t:ident) => {
+        impl<I: Check<($t, $t)>> Eval for Add<$t, I> {
+            type Input = ($t, $t);
+            type Output = $t;
+            fn eval(&self, x: ($t, $t)) -> Option<$t> {
+                if self.i.check(&x) {Some(x.0 + x.1)} else {None}
+            }
+        }
+
+        impl<I: Check<($t, $t)>> Eval for Mul<$t, I> {
+            type Input = ($t, $t);
+            type Output = $t;
+            fn eval(&self, x: ($t, $t)) -> Option<$t> {
+                if self.i.check(&x) {Some(x.0 * x.1)} else {None}
+            }
+        }
+
+        impl<I: Check<$t>> Eval for Neg<$t, I> {
+            type Input = $t;
+            type Output = $t;
+            fn eval(&self, x: $t) -> Option<$t> {
+                if self.i.check(&x) {Some(-x)} else {None}
+            }
+        }
+
+        impl<I: Check<$t>> Eval for Abs<$t, I> {
+            type Input = $t;
+            type Output = $t;
+            fn eval(&self, x: $t) -> Option<$t> {
+                if self.i.check(&x) {Some(x.abs())} else {None}
+            }
+        }
+
+        impl<I: Check<$t>> Eval for IsNan<$t, I> {
+            type Input = $t;
+            type Output = bool;
+            fn eval(&self, x: $t) -> Option<bool> {
+                if self.i.check(&x) {Some(x.is_nan())} else {None}
+            }
+        }
+
+        impl<I: Check<$t>> Eval for IsFinite<$t, I> {
+            type Input = $t;
+            type Output = bool;
+            fn eval(&self, x: $t) -> Option<bool> {
+                if self.i.check(&x) {Some(x.is_finite())} else {None}
+            }
+        }
+
+        impl<I: Check<$t>> Eval for SignBit<$t, I> {
+            type Input = $t;
+            type Output = bool;
+            fn eval(&self, x: $t) -> Option<bool> {
+                if self.i.check(&x) {Some(x.is_sign_negative())} else {None}
+            }
+        }
+    };
+}
+
+float_impl!{f32}
+float_impl!{f64}
+
+// `()` is `true_1`, which can be used in a branch of `If` or `IfK`.
+// The input type is then decided by the other branch.
+
+impl<X: Clone, Co, Tr, Fa, I> Eval for If<Co, Tr, Fa, I>
+    where Co: Eval<Input = X, Output = bool>,
+          Tr: Eval<Input = X>,
+          Fa: Eval<Input = X, Output = Tr::Output>,
+          I: Check<X>
+{
+    type Input = X;
+    type Output = Tr::Output;
+    fn eval(&self, x: X) -> Option<Self::Output> {
+        if !self.i.check(&x) {return None};
+        if self.co.eval(x.clone())? {self.tr.eval(x)} else {self.fa.eval(x)}
+    }
+}
+
+impl<X: Clone, Co, Fa, I> Eval for If<Co, (), Fa, I>
+    where Co: Eval<Input = X, Output = bool>,
+          Fa: Eval<Input = X, Output = bool>,
+          I: Check<X>
+{
+    type Input = X;
+    type Output = bool;
+    fn eval(&self, x: X) -> Option<bool> {
+        if !self.i.check(&x) {return None};
+        if self.co.eval(x.clone())? {Some(true)} else {self.fa.eval(x)}
+    }
+}
+
+impl<X: Clone, Co, Tr, I> Eval for If<Co, Tr, (), I>
+    where Co: Eval<Input = X, Output = bool>,
+          Tr: Eval<Input = X, Output = bool>,
+          I: Check<X>
+{
+    type Input = X;
+    type Output = bool;
+    fn eval(&self, x: X) -> Option<bool> {
+        if !self.i.check(&x) {return None};
+        if self.co.eval(x.clone())? {self.tr.eval(x)} else {Some(true)}
+    }
+}
+
+impl<X, A, B, I> Eval for Both<A, B, I>
+    where A: Eval<Input = X, Output = bool>, B: Check<X>, I: Check<X>
+{
+    type Input = X;
+    type Output = bool;
+    fn eval(&self, x: X) -> Option<bool> {
+        if !self.i.check(&x) {return None};
+        let b = self.b.check(&x);
+        Some(self.a.eval(x)? && b)
+    }
+}
+
+impl<X, A, B, I> Eval for Either<A, B, I>
+    where A: Eval<Input = X, Output = bool>, B: Check<X>, I: Check<X>
+{
+    type Input = X;
+    type Output = bool;
+    fn eval(&self, x: X) -> Option<bool> {
+        if !self.i.check(&x) {return None};
+        let b = self.b.check(&x);
+        Some(self.a.eval(x)? || b)
+    }
+}
+
+impl<X, A, I> Eval for Complement<A, I>
+    where A: Eval<Input = X, Output = bool>, I: Check<X>
+{
+    type Input = X;
+    type Output = bool;
+    fn eval(&self, x: X) -> Option<bool> {
+        if !self.i.check(&x) {return None};
+        Some(!self.a.eval(x)?)
+    }
+}
+
+impl<F: Eval> Eval for Union<F> {
+    type Input = F::Input;
+    type Output = F::Output;
+    fn eval(&self, x: F::Input) -> Option<F::Output> {self.f.eval(x)}
+}
+
+impl<G, F> Eval for Compose<G, F>
+    where F: Eval, G: Eval<Input = F::Output>
+{
+    type Input = F::Input;
+    type Output = G::Output;
+    fn eval(&self, x: F::Input) -> Option<G::Output> {
+        self.g.eval(self.f.eval(x)?)
+    }
+}
+
+impl<T: Clone, Co, Tr, Fa, I> Eval for IfK<T, Co, Tr, Fa, I>
+    where Co: Eval<Input = T, Output = bool>,
+          Tr: Eval,
+          Fa: Eval<Input = Tr::Input, Output = Tr::Output>,
+          I: Check<Tr::Input>
+{
+    type Input = Tr::Input;
+    type Output = Tr::Output;
+    fn eval(&self, x: Tr::Input) -> Option<Self::Output> {
+        if !self.i.check(&x) {return None};
+        if self.co.eval(self.k.clone())? {self.tr.eval(x)} else {self.fa.eval(x)}
+    }
+}
+
+impl<T: Clone, Co, Fa, I> Eval for IfK<T, Co, (), Fa, I>
+    where Co: Eval<Input = T, Output = bool>,
+          Fa: Eval<Output = bool>,
+          I: Check<Fa::Input>
+{
+    type Input = Fa::Input;
+    type Output = bool;
+    fn eval(&self, x: Fa::Input) -> Option<bool> {
+        if !self.i.check(&x) {return None};
+        if self.co.eval(self.k.clone())? {Some(true)} else {self.fa.eval(x)}
+    }
+}
+
+impl<T: Clone, Co, Tr, I> Eval for IfK<T, Co, Tr, (), I>
+    where Co: Eval<Input = T, Output = bool>,
+          Tr: Eval<Output = bool>,
+          I: Check<Tr::Input>
+{
+    type Input = Tr::Input;
+    type Output = bool;
+    fn eval(&self, x: Tr::Input) -> Option<bool> {
+        if !self.i.check(&x) {return None};
+        if self.co.eval(self.k.clone())? {self.tr.eval(x)} else {Some(true)}
+    }
+}
+
+// Constants in the type are evaluated as runtime constants.
+macro_rules! const_impl {
+    ($a:ident => $b:ident) => {
+        impl<const K: u64, I: Dynamic> Eval for $a<K, I>
+            where $b<u64, I::Lift>: Eval
+        {
+            type Input = <$b<u64, I::Lift> as Eval>::Input;
+            type Output = <$b<u64, I::Lift> as Eval>::Output;
+            fn eval(&self, x: Self::Input) -> Option<Self::Output> {self.dynamic().eval(x)}
+        }
+
+        impl<X, const K: u64, I: Dynamic> Check<X> for $a<K, I>
+            where $b<u64, I::Lift>: Check<X>
+        {
+            fn check(&self, x: &X) -> bool {self.dynamic().check(x)}
+        }
+    };
+}
+
+const_impl!{EqC => EqK}
+const_impl!{GeC => GeK}
+const_impl!{LtC => LtK}
+const_impl!{AddC => AddK}
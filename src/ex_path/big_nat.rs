@@ -0,0 +1,314 @@
+use *;
+
+// There is no largest `BigNat`, so these are the rules of `nat.rs` without the cases at `max`.
+// For example, `∃even{(>= k)}` is `true_1` since some even number is greater than `k`.
+
+ex_impl!{K AddK<BigNat>, GeK<BigNat>}
+ex_impl!{If<GeK<BigNat>, Even<BigNat>, Odd<BigNat>>, ()}
+ex_impl!{If<GeK<BigNat>, Odd<BigNat>, Even<BigNat>>, ()}
+ex_impl!{If<LtK<BigNat>, Even<BigNat>, Odd<BigNat>>, ()}
+ex_impl!{If<LtK<BigNat>, Odd<BigNat>, Even<BigNat>>, ()}
+
+ex_impl!{Add<BigNat, (Even<BigNat>, Odd<BigNat>)>, Odd<BigNat>}
+ex_impl!{Add<BigNat, (Odd<BigNat>, Even<BigNat>)>, Odd<BigNat>}
+ex_impl!{Add<BigNat, (Even<BigNat>, Even<BigNat>)>, Even<BigNat>}
+
+// `∃add{(odd, odd)} => if((< 2), false_1, even)`
+impl ExPath for Add<BigNat, (Odd<BigNat>, Odd<BigNat>)> {
+    type Lift = If<LtK<BigNat>, False1<BigNat>, Even<BigNat>>;
+    fn ex_path(&self) -> Self::Lift {
+        If {co: LtK {k: 2u8.into(), i: ()}, tr: False1::default(), fa: Even::default(), i: ()}
+    }
+}
+
+// `∃if((< k), false_1, even) => true_1`
+// There is an even number `>= k`.
+ex_impl!{If<LtK<BigNat>, False1<BigNat>, Even<BigNat>>, ()}
+// `∃even{if((< k), false_1, even)} => id`
+ex_impl!{Even<BigNat, If<LtK<BigNat>, False1<BigNat>, Even<BigNat>>>, Id<bool>}
+
+impl ExPath for GeK<BigNat> {
+    type Lift = IfK<BigNat, EqK<BigNat>, Id<bool>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k.clone(), co: EqK {k: BigNat::default(), i: ()}, tr: Id::default(), fa: (), i: ()}
+    }
+}
+
+impl ExPath for LtK<BigNat> {
+    type Lift = IfK<BigNat, EqK<BigNat>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k.clone(), co: EqK {k: BigNat::default(), i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(>= k){even}`
+// `\(x: bool) = if k == 0 {id(x)} else {true_1(x)}`
+impl ExPath for GeK<BigNat, Even<BigNat>> {
+    type Lift = IfK<BigNat, EqK<BigNat>, Id<bool>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k.clone(), co: EqK {k: BigNat::default(), i: ()}, tr: Id::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(>= k){odd}`
+// `\(x: bool) = if k < 2 {id(x)} else {true_1(x)}`
+impl ExPath for GeK<BigNat, Odd<BigNat>> {
+    type Lift = IfK<BigNat, LtK<BigNat>, Id<bool>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k.clone(), co: LtK {k: 2u8.into(), i: ()}, tr: Id::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(< k){even}`
+// `\(x: bool) = if k == 0 {not(x)} else {true_1(x)}`
+impl ExPath for LtK<BigNat, Even<BigNat>> {
+    type Lift = IfK<BigNat, EqK<BigNat>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k.clone(), co: EqK {k: BigNat::default(), i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(< k){odd}`
+// `\(x: bool) = if k < 2 {not(x)} else {true_1(x)}`
+impl ExPath for LtK<BigNat, Odd<BigNat>> {
+    type Lift = IfK<BigNat, LtK<BigNat>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k.clone(), co: LtK {k: 2u8.into(), i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+
+impl ExPath for EqK<BigNat, Even<BigNat>> {
+    type Lift = IfK<BigNat, Even<BigNat>, (), Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k.clone(), co: Even::default(), tr: (), fa: Not::default(), i: ()}
+    }
+}
+
+impl ExPath for EqK<BigNat, Odd<BigNat>> {
+    type Lift = IfK<BigNat, Odd<BigNat>, (), Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k.clone(), co: Odd::default(), tr: (), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃add(k){even}`
+// `\(x: nat) = if x >= k {if even(k) {even(x)} else {odd(x)}} else {false}`
+impl ExPath for AddK<BigNat, Even<BigNat>> {
+    type Lift = If<GeK<BigNat>, IfK<BigNat, Even<BigNat>, Even<BigNat>, Odd<BigNat>>, False1<BigNat>>;
+    fn ex_path(&self) -> Self::Lift {
+        If {
+            co: GeK {k: self.k.clone(), i: ()},
+            tr: IfK {k: self.k.clone(), co: Even::default(), tr: Even::default(), fa: Odd::default(), i: ()},
+            fa: False1::default(), i: ()
+        }
+    }
+}
+
+// `∃add(k){odd}`
+// `\(x: nat) = if x >= k {if odd(k) {even(x)} else {odd(x)}} else {false}`
+impl ExPath for AddK<BigNat, Odd<BigNat>> {
+    type Lift = If<GeK<BigNat>, IfK<BigNat, Odd<BigNat>, Even<BigNat>, Odd<BigNat>>, False1<BigNat>>;
+    fn ex_path(&self) -> Self::Lift {
+        If {
+            co: GeK {k: self.k.clone(), i: ()},
+            tr: IfK {k: self.k.clone(), co: Odd::default(), tr: Even::default(), fa: Odd::default(), i: ()},
+            fa: False1::default(), i: ()
+        }
+    }
+}
+
+// `∃if((>= k), if even(k2) {even} else {odd}, false_1) => true_1`
+// `false` is returned for `x < k` or when `x` has the other parity.
+ex_impl!{If<GeK<BigNat>, IfK<BigNat, Even<BigNat>, Even<BigNat>, Odd<BigNat>>, False1<BigNat>>, ()}
+// `∃if((>= k), if odd(k2) {even} else {odd}, false_1) => true_1`
+ex_impl!{If<GeK<BigNat>, IfK<BigNat, Odd<BigNat>, Even<BigNat>, Odd<BigNat>>, False1<BigNat>>, ()}
+
+// `∃even{(= k)}`
+// `\(x) = if even(k) {id(x)} else {not(x)}`
+impl ExPath for Even<BigNat, EqK<BigNat>> {
+    type Lift = IfK<BigNat, Even<BigNat>, Id<bool>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k.clone(), co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃odd{(= k)}`
+// `\(x: bool) = if odd(k) {id(x)} else {not(x)}`
+impl ExPath for Odd<BigNat, EqK<BigNat>> {
+    type Lift = IfK<BigNat, Odd<BigNat>, Id<bool>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k.clone(), co: Odd::default(), tr: Id::default(), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃(= k){= k2}`
+// `\(x: bool) = if k == k2 {id(x)} else {not(x)}`
+impl ExPath for EqK<BigNat, EqK<BigNat>> {
+    type Lift = IfK<BigNat, EqK<BigNat>, Id<bool>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k.clone(), co: EqK {k: self.k.clone(), i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃(< k){= k2}`
+// `\(x: bool) = if k2 < k {id(x)} else {not(x)}`
+impl ExPath for LtK<BigNat, EqK<BigNat>> {
+    type Lift = IfK<BigNat, LtK<BigNat>, Id<bool>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k.clone(), co: LtK {k: self.k.clone(), i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃(>= k){= k2}`
+// `\(x: bool) = if k2 >= k {id(x)} else {not(x)}`
+impl ExPath for GeK<BigNat, EqK<BigNat>> {
+    type Lift = IfK<BigNat, GeK<BigNat>, Id<bool>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k.clone(), co: GeK {k: self.k.clone(), i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃add(k){= k2} => (= k + k2)`
+impl ExPath for AddK<BigNat, EqK<BigNat>> {
+    type Lift = EqK<BigNat>;
+    fn ex_path(&self) -> Self::Lift {
+        EqK {k: &self.k + &self.i.k, i: ()}
+    }
+}
+
+// `∃even{(>= k)} => true_1`
+ex_impl!{Even<BigNat, GeK<BigNat>>, ()}
+// `∃odd{(>= k)} => true_1`
+ex_impl!{Odd<BigNat, GeK<BigNat>>, ()}
+
+// `∃(= k){>= k2}`
+// `\(x: bool) = if k < k2 {not(x)} else {true_1(x)}`
+impl ExPath for EqK<BigNat, GeK<BigNat>> {
+    type Lift = IfK<BigNat, LtK<BigNat>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k.clone(), co: LtK {k: self.i.k.clone(), i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(< k){(>= k2)}`
+// `\(x: bool) = if k2 >= k {not(x)} else {true_1(x)}`
+impl ExPath for LtK<BigNat, GeK<BigNat>> {
+    type Lift = IfK<BigNat, GeK<BigNat>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k.clone(), co: GeK {k: self.k.clone(), i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(>= k){(>= k2)}`
+// `\(x: bool) = if k2 < k {true_1(x)} else {id(x)}`
+impl ExPath for GeK<BigNat, GeK<BigNat>> {
+    type Lift = IfK<BigNat, LtK<BigNat>, (), Id<bool>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k.clone(), co: LtK {k: self.k.clone(), i: ()}, tr: (), fa: Id::default(), i: ()}
+    }
+}
+
+// `∃add(k){(>= k2)} <=> (>= k + k2)`
+impl ExPath for AddK<BigNat, GeK<BigNat>> {
+    type Lift = GeK<BigNat>;
+    fn ex_path(&self) -> Self::Lift {
+        GeK {k: &self.k + &self.i.k, i: ()}
+    }
+}
+
+// `∃even{(< k)}`
+// `\(x: bool) = if k < 2 {if k == 0 {not(x)} else {id(x)}} else {true_1(x)}`
+impl ExPath for Even<BigNat, LtK<BigNat>> {
+    type Lift = IfK<BigNat, LtK<BigNat>, IfK<BigNat, EqK<BigNat>, False1<bool>, Id<bool>>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k.clone(), co: LtK {k: 2u8.into(), i: ()},
+            tr: IfK {k: self.i.k.clone(), co: EqK {k: BigNat::default(), i: ()},
+                     tr: False1::default(), fa: Id::default(), i: ()},
+            fa: (), i: ()
+        }
+    }
+}
+
+// `∃odd{(< k)}`
+// `\(x: bool) = if k < 2 {if k == 0 {false_1(x)} else {not(x)}} else {true_1}`
+impl ExPath for Odd<BigNat, LtK<BigNat>> {
+    type Lift = IfK<BigNat, LtK<BigNat>, IfK<BigNat, EqK<BigNat>, False1<bool>, Not>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k.clone(), co: LtK {k: 2u8.into(), i: ()},
+            tr: IfK {k: self.i.k.clone(), co: EqK {k: BigNat::default(), i: ()},
+                     tr: False1::default(), fa: Not::default(), i: ()},
+            fa: (), i: ()
+        }
+    }
+}
+
+// `∃(= k){(< k2)}`
+// `\(x: bool) = if k2 == 0 {false_1(x)} else {if k < k2 {if k2 == 1 {id(x)} else {true_1(x)}} else {not(x)}}`
+impl ExPath for EqK<BigNat, LtK<BigNat>> {
+    type Lift = IfK<BigNat, EqK<BigNat>, False1<bool>,
+                    IfK<BigNat, LtK<BigNat>, IfK<BigNat, EqK<BigNat>, Id<bool>, ()>, Not>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k.clone(), co: EqK {k: BigNat::default(), i: ()}, i: (),
+            tr: False1::default(),
+            fa: IfK {
+                k: self.k.clone(), co: LtK {k: self.i.k.clone(), i: ()}, fa: Not::default(), i: (),
+                tr: IfK {k: self.i.k.clone(), co: EqK {k: 1u8.into(), i: ()}, tr: Id::default(), fa: (), i: ()}
+            }
+        }
+    }
+}
+
+// `∃(< k){(< k2)}`
+// `\(x: bool) = if k2 == 0 {false_1(x)} else {if k == 0 {not(x)} else {if k >= k2 {id(x)} else {true_1(x)}}}`
+impl ExPath for LtK<BigNat, LtK<BigNat>> {
+    type Lift = IfK<BigNat, EqK<BigNat>, False1<bool>,
+                    IfK<BigNat, EqK<BigNat>, Not, IfK<BigNat, GeK<BigNat>, Id<bool>, ()>>
+                >;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k.clone(), co: EqK {k: BigNat::default(), i: ()}, i: (),
+            tr: False1::default(),
+            fa: IfK {
+                k: self.k.clone(), co: EqK {k: BigNat::default(), i: ()}, tr: Not::default(), i: (),
+                fa: IfK {k: self.k.clone(), co: GeK {k: self.i.k.clone(), i: ()},
+                         tr: Id::default(), fa: (), i: ()}
+            }
+        }
+    }
+}
+
+// `∃(>= k){(< k2)}`
+// `\(x: bool) = if k2 == 0 {false_1(x)} else {if k == 0 {id(x)} else {if k < k2 {true_1(x)} else {not(x)}}}`
+impl ExPath for GeK<BigNat, LtK<BigNat>> {
+    type Lift = IfK<BigNat, EqK<BigNat>, False1<bool>,
+                    IfK<BigNat, EqK<BigNat>, Id<bool>, IfK<BigNat, LtK<BigNat>, (), Not>>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k.clone(), co: EqK {k: BigNat::default(), i: ()}, tr: False1::default(), i: (),
+            fa: IfK {
+                k: self.k.clone(), co: EqK {k: BigNat::default(), i: ()}, tr: Id::default(), i: (),
+                fa: IfK {k: self.k.clone(), co: LtK {k: self.i.k.clone(), i: ()}, tr: (), fa: Not::default(), i: ()}
+            }
+        }
+    }
+}
+
+// `∃add(k){(< k2)} => [k, k + k2)`
+impl ExPath for AddK<BigNat, LtK<BigNat>> {
+    type Lift = RangeK<BigNat>;
+    fn ex_path(&self) -> Self::Lift {
+        RangeK {lo: self.k.clone(), hi: &self.k + &self.i.k, i: ()}
+    }
+}
+
+// `∃[lo, hi)`
+// `\(x: bool) = if lo < hi {true_1(x)} else {not(x)}`
+impl ExPath for RangeK<BigNat> {
+    type Lift = IfK<BigNat, LtK<BigNat>, (), Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.lo.clone(), co: LtK {k: self.hi.clone(), i: ()}, tr: (), fa: Not::default(), i: ()}
+    }
+}
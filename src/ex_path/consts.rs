@@ -0,0 +1,55 @@
+use *;
+
+// Constants in the type can not be computed with, e.g. `∃add(K){(= K2)} => (= K + K2)`,
+// so existential paths that depend on the value of a constant use the runtime rules.
+// For the same reason, there are no paths with constants in the lift,
+// and theorems about specific constants still compare captured variables at runtime.
+macro_rules! const_impl {
+    ($a:ident => $b:ident) => {
+        impl<const K: u64, I: Dynamic> ExPath for $a<K, I>
+            where $b<u64, I::Lift>: ExPath
+        {
+            type Lift = <$b<u64, I::Lift> as ExPath>::Lift;
+            fn ex_path(&self) -> Self::Lift {self.dynamic().ex_path()}
+        }
+    };
+    (AddC , $i:ty $(, $k2:ident)*) => {
+        impl<const K: u64 $(, const $k2: u64)*> ExPath for AddC<K, $i> {
+            type Lift = <AddK<u64, <$i as Dynamic>::Lift> as ExPath>::Lift;
+            fn ex_path(&self) -> Self::Lift {self.dynamic().ex_path()}
+        }
+    };
+}
+
+const_impl!{EqC => EqK}
+const_impl!{GeC => GeK}
+const_impl!{LtC => LtK}
+
+// `∃add(K) => (>= K)`
+impl<const K: u64> ExPath for AddC<K> {
+    type Lift = GeC<K>;
+    fn ex_path(&self) -> Self::Lift {GeC {i: ()}}
+}
+
+const_impl!{AddC, Even<u64>}
+const_impl!{AddC, Odd<u64>}
+const_impl!{AddC, EqC<K2>, K2}
+const_impl!{AddC, GeC<K2>, K2}
+const_impl!{AddC, LtC<K2>, K2}
+
+// `∃even{(>= K)}` and `∃odd{(>= K)}` depend on the value of `K`.
+macro_rules! parity_impl {
+    ($p:ident , $a:ident => $b:ident) => {
+        impl<const K: u64> ExPath for $p<u64, $a<K>> {
+            type Lift = <$p<u64, $b<u64>> as ExPath>::Lift;
+            fn ex_path(&self) -> Self::Lift {self.dynamic().ex_path()}
+        }
+    };
+}
+
+parity_impl!{Even, EqC => EqK}
+parity_impl!{Even, GeC => GeK}
+parity_impl!{Even, LtC => LtK}
+parity_impl!{Odd, EqC => EqK}
+parity_impl!{Odd, GeC => GeK}
+parity_impl!{Odd, LtC => LtK}
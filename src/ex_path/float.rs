@@ -0,0 +1,87 @@
+use *;
+
+macro_rules! float_impl {
+    ($t:ident) => {
+        // Every float is reached by `x + 0`, `x * 1` and `-(-x)`, including NaNs and infinities.
+        ex_impl!{Neg<$t>, ()}
+        ex_impl!{IsNan<$t>, ()}
+        ex_impl!{IsFinite<$t>, ()}
+        ex_impl!{SignBit<$t>, ()}
+
+        // `∃abs => if(sign_bit, false_1, true_1)`
+        // The sign bit is cleared for all values, including NaNs.
+        impl ExPath for Abs<$t> {
+            type Lift = If<SignBit<$t>, False1<$t>, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                If {co: SignBit::default(), tr: False1::default(), fa: (), i: ()}
+            }
+        }
+
+        // `∃add{(is_finite, is_finite)} => if(is_nan, false_1, true_1)`
+        // The sum of two finite numbers might overflow to infinity, but is never NaN.
+        impl ExPath for Add<$t, (IsFinite<$t>, IsFinite<$t>)> {
+            type Lift = If<IsNan<$t>, False1<$t>, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                If {co: IsNan::default(), tr: False1::default(), fa: (), i: ()}
+            }
+        }
+
+        // `∃mul{(is_finite, is_finite)} => if(is_nan, false_1, true_1)`
+        // NaN requires `0 * inf`, but the product might overflow to infinity.
+        impl ExPath for Mul<$t, (IsFinite<$t>, IsFinite<$t>)> {
+            type Lift = If<IsNan<$t>, False1<$t>, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                If {co: IsNan::default(), tr: False1::default(), fa: (), i: ()}
+            }
+        }
+
+        ex_impl!{Neg<$t, IsNan<$t>>, IsNan<$t>}
+        ex_impl!{Neg<$t, IsFinite<$t>>, IsFinite<$t>}
+
+        // `∃neg{sign_bit} => if(sign_bit, false_1, true_1)`
+        impl ExPath for Neg<$t, SignBit<$t>> {
+            type Lift = If<SignBit<$t>, False1<$t>, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                If {co: SignBit::default(), tr: False1::default(), fa: (), i: ()}
+            }
+        }
+
+        // `∃abs{is_nan} => if(sign_bit, false_1, is_nan)`
+        impl ExPath for Abs<$t, IsNan<$t>> {
+            type Lift = If<SignBit<$t>, False1<$t>, IsNan<$t>>;
+            fn ex_path(&self) -> Self::Lift {
+                If {co: SignBit::default(), tr: False1::default(), fa: IsNan::default(), i: ()}
+            }
+        }
+
+        // `∃abs{is_finite} => if(sign_bit, false_1, is_finite)`
+        impl ExPath for Abs<$t, IsFinite<$t>> {
+            type Lift = If<SignBit<$t>, False1<$t>, IsFinite<$t>>;
+            fn ex_path(&self) -> Self::Lift {
+                If {co: SignBit::default(), tr: False1::default(), fa: IsFinite::default(), i: ()}
+            }
+        }
+
+        // A NaN is never finite.
+        ex_impl!{IsNan<$t, IsFinite<$t>>, Not}
+        ex_impl!{IsFinite<$t, IsNan<$t>>, Not}
+        // Both signs exist for NaNs and finite numbers.
+        ex_impl!{SignBit<$t, IsNan<$t>>, ()}
+        ex_impl!{SignBit<$t, IsFinite<$t>>, ()}
+        ex_impl!{SignBit<$t, (IsFinite<$t>, IsFinite<$t>)>}
+        ex_impl!{IsNan<$t, SignBit<$t>>, ()}
+        ex_impl!{IsFinite<$t, SignBit<$t>>, ()}
+
+        // Existential paths under `if(sign_bit, false_1, true_1)`, which are the non-negative values.
+        ex_impl!{SignBit<$t, If<SignBit<$t>, False1<$t>, ()>>, Not}
+        ex_impl!{IsNan<$t, If<SignBit<$t>, False1<$t>, ()>>, ()}
+        ex_impl!{IsFinite<$t, If<SignBit<$t>, False1<$t>, ()>>, ()}
+        // Existential paths under `if(is_nan, false_1, true_1)`, which are the non-NaN values.
+        ex_impl!{SignBit<$t, If<IsNan<$t>, False1<$t>, ()>>, ()}
+        ex_impl!{IsNan<$t, If<IsNan<$t>, False1<$t>, ()>>, Not}
+        ex_impl!{IsFinite<$t, If<IsNan<$t>, False1<$t>, ()>>, ()}
+    };
+}
+
+float_impl!{f32}
+float_impl!{f64}
@@ -0,0 +1,353 @@
+use *;
+
+macro_rules! int_impl {
+    ($t:ident) => {
+        // `∃add(k)`
+        // `\(x: int) = if k >= 0 {(>= min + k)(x)} else {(<= max + k)(x)}`
+        // The constant wraps around in the unused branch.
+        impl ExPath for AddK<$t> {
+            type Lift = IfK<$t, GeK<$t>, GeK<$t>, LeK<$t>>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {
+                    k: self.k, co: GeK {k: 0, i: ()}, i: (),
+                    tr: GeK {k: $t::MIN.wrapping_add(self.k), i: ()},
+                    fa: LeK {k: $t::MAX.wrapping_add(self.k), i: ()},
+                }
+            }
+        }
+
+        // There are both even and odd numbers left when excluding a single number.
+        ex_impl!{Even<$t, NeqK<$t>>, ()}
+        ex_impl!{Odd<$t, NeqK<$t>>, ()}
+
+        // `∃neg => (!= min)`, since `-max` is `min + 1`.
+        impl ExPath for Neg<$t> {
+            type Lift = NeqK<$t>;
+            fn ex_path(&self) -> Self::Lift {NeqK {k: $t::MIN, i: ()}}
+        }
+
+        // `∃neg{even} => even{(!= min)}`, since `min` is even.
+        impl ExPath for Neg<$t, Even<$t>> {
+            type Lift = Even<$t, NeqK<$t>>;
+            fn ex_path(&self) -> Self::Lift {
+                Even {t: PhantomData, i: NeqK {k: $t::MIN, i: ()}}
+            }
+        }
+
+        ex_impl!{Neg<$t, Odd<$t>>, Odd<$t>}
+
+        // `∃neg{¬is_neg} => ¬is_pos ∧ (!= min)`, which is `[-max, 0]`.
+        impl ExPath for Neg<$t, Complement<IsNeg<$t>>> {
+            type Lift = Both<Complement<IsPos<$t>>, NeqK<$t>>;
+            fn ex_path(&self) -> Self::Lift {
+                Both::new(Complement::new(IsPos::default()), NeqK {k: $t::MIN, i: ()})
+            }
+        }
+
+        // There are non-positive numbers of both parities, also when excluding a single number.
+        ex_impl!{Even<$t, Both<Complement<IsPos<$t>>, NeqK<$t>>>, ()}
+        ex_impl!{Odd<$t, Both<Complement<IsPos<$t>>, NeqK<$t>>>, ()}
+
+        // `∃abs => ¬is_neg`, since `abs(min)` overflows.
+        // This is `(>= 0)` without a captured constant, such that `abs[even]` type checks.
+        impl ExPath for Abs<$t> {
+            type Lift = Complement<IsNeg<$t>>;
+            fn ex_path(&self) -> Self::Lift {Complement::new(IsNeg::default())}
+        }
+
+        // `∃abs{even} => even{¬is_neg}`
+        impl ExPath for Abs<$t, Even<$t>> {
+            type Lift = Even<$t, Complement<IsNeg<$t>>>;
+            fn ex_path(&self) -> Self::Lift {
+                Even {t: PhantomData, i: Complement::new(IsNeg::default())}
+            }
+        }
+
+        // `∃abs{odd} => odd{¬is_neg}`
+        impl ExPath for Abs<$t, Odd<$t>> {
+            type Lift = Odd<$t, Complement<IsNeg<$t>>>;
+            fn ex_path(&self) -> Self::Lift {
+                Odd {t: PhantomData, i: Complement::new(IsNeg::default())}
+            }
+        }
+
+        // There are non-negative numbers of both parities.
+        ex_impl!{Even<$t, Complement<IsNeg<$t>>>, ()}
+        ex_impl!{Odd<$t, Complement<IsNeg<$t>>>, ()}
+
+        // There are negative and non-negative numbers of both parities,
+        // also when excluding a single number.
+        ex_impl!{IsNeg<$t>, ()}
+        ex_impl!{IsNeg<$t, Even<$t>>, ()}
+        ex_impl!{IsNeg<$t, Odd<$t>>, ()}
+        ex_impl!{IsNeg<$t, NeqK<$t>>, ()}
+        ex_impl!{IsPos<$t>, ()}
+        ex_impl!{IsPos<$t, Even<$t>>, ()}
+        ex_impl!{IsPos<$t, Odd<$t>>, ()}
+        ex_impl!{IsPos<$t, NeqK<$t>>, ()}
+        ex_impl!{IsNeg<$t, NonZero<$t>>, ()}
+        ex_impl!{IsNeg<$t, (NonZero<$t>, NonZero<$t>)>}
+
+        ex_impl!{NonZero<$t>, ()}
+        ex_impl!{NonZero<$t, Even<$t>>, ()}
+        ex_impl!{NonZero<$t, Odd<$t>>, Id<bool>}
+
+        // `∃mul{(non_zero, non_zero)} => non_zero`
+        // There are no zero divisors, and every non-zero number is reached by `x * 1`.
+        ex_impl!{Mul<$t, (NonZero<$t>, NonZero<$t>)>, NonZero<$t>}
+    };
+}
+
+int_impl!{i8}
+int_impl!{i16}
+int_impl!{i32}
+int_impl!{i64}
+
+// The same rules as for natural numbers, where `min` takes the place of `0`.
+// Like `0`, `min` is even and `max` is odd.
+macro_rules! cmp_impl {
+    ($t:ident) => {
+        impl ExPath for GeK<$t> {
+            type Lift = IfK<$t, EqK<$t>, Id<bool>, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.k, co: EqK {k: $t::MIN, i: ()}, tr: Id::default(), fa: (), i: ()}
+            }
+        }
+
+        impl ExPath for LtK<$t> {
+            type Lift = IfK<$t, EqK<$t>, Not, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.k, co: EqK {k: $t::MIN, i: ()}, tr: Not::default(), fa: (), i: ()}
+            }
+        }
+
+        // `∃(>= k){even}`
+        // `\(x: bool) = if k == min {id(x)} else {if k == max {not(x)} else {true_1(x)}}`
+        impl ExPath for GeK<$t, Even<$t>> {
+            type Lift = IfK<$t, EqK<$t>, Id<bool>, IfK<$t, EqK<$t>, Not, ()>>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {
+                    k: self.k, co: EqK {k: $t::MIN, i: ()}, tr: Id::default(), i: (),
+                    fa: IfK {k: self.k, co: EqK {k: $t::MAX, i: ()}, tr: Not::default(), fa: (), i: ()}
+                }
+            }
+        }
+
+        // `∃(>= k){odd}`
+        // `\(x: bool) = if k < min + 2 {id(x)} else {true_1(x)}`
+        impl ExPath for GeK<$t, Odd<$t>> {
+            type Lift = IfK<$t, LtK<$t>, Id<bool>, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.k, co: LtK {k: $t::MIN + 2, i: ()}, tr: Id::default(), fa: (), i: ()}
+            }
+        }
+
+        // `∃(< k){even}`
+        // `\(x: bool) = if k == min {not(x)} else {if k == max {id(x)} else {true_1(x)}}`
+        impl ExPath for LtK<$t, Even<$t>> {
+            type Lift = IfK<$t, EqK<$t>, Not, IfK<$t, EqK<$t>, Id<bool>, ()>>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {
+                    k: self.k, co: EqK {k: $t::MIN, i: ()}, tr: Not::default(), i: (),
+                    fa: IfK {k: self.k, co: EqK {k: $t::MAX, i: ()}, tr: Id::default(), fa: (), i: ()}
+                }
+            }
+        }
+
+        // `∃(< k){odd}`
+        // `\(x: bool) = if k < min + 2 {not(x)} else {true_1(x)}`
+        impl ExPath for LtK<$t, Odd<$t>> {
+            type Lift = IfK<$t, LtK<$t>, Not, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.k, co: LtK {k: $t::MIN + 2, i: ()}, tr: Not::default(), fa: (), i: ()}
+            }
+        }
+
+        impl ExPath for EqK<$t, Even<$t>> {
+            type Lift = IfK<$t, Even<$t>, (), Not>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.k, co: Even::default(), tr: (), fa: Not::default(), i: ()}
+            }
+        }
+
+        impl ExPath for EqK<$t, Odd<$t>> {
+            type Lift = IfK<$t, Odd<$t>, (), Not>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.k, co: Odd::default(), tr: (), fa: Not::default(), i: ()}
+            }
+        }
+
+        // `∃even{(= k)}`
+        // `\(x: bool) = if even(k) {id(x)} else {not(x)}`
+        impl ExPath for Even<$t, EqK<$t>> {
+            type Lift = IfK<$t, Even<$t>, Id<bool>, Not>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.i.k, co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()}
+            }
+        }
+
+        // `∃odd{(= k)}`
+        // `\(x: bool) = if odd(k) {id(x)} else {not(x)}`
+        impl ExPath for Odd<$t, EqK<$t>> {
+            type Lift = IfK<$t, Odd<$t>, Id<bool>, Not>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.i.k, co: Odd::default(), tr: Id::default(), fa: Not::default(), i: ()}
+            }
+        }
+
+        // `∃even{(>= k)}`
+        // `\(x: bool) = if k == max {not(x)} else {true_1(x)}`
+        impl ExPath for Even<$t, GeK<$t>> {
+            type Lift = IfK<$t, EqK<$t>, Not, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.i.k, co: EqK {k: $t::MAX, i: ()}, tr: Not::default(), fa: (), i: ()}
+            }
+        }
+
+        // `∃odd{(>= k)}`
+        // `\(x: bool) = if k == max {id(x)} else {true_1(x)}`
+        impl ExPath for Odd<$t, GeK<$t>> {
+            type Lift = IfK<$t, EqK<$t>, Id<bool>, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.i.k, co: EqK {k: $t::MAX, i: ()}, tr: Id::default(), fa: (), i: ()}
+            }
+        }
+
+        // `∃even{(< k)}`
+        // `\(x: bool) = if k < min + 2 {if k == min {false_1(x)} else {id(x)}} else {true_1(x)}`
+        impl ExPath for Even<$t, LtK<$t>> {
+            type Lift = IfK<$t, LtK<$t>, IfK<$t, EqK<$t>, False1<bool>, Id<bool>>, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {
+                    k: self.i.k, co: LtK {k: $t::MIN + 2, i: ()}, fa: (), i: (),
+                    tr: IfK {k: self.i.k, co: EqK {k: $t::MIN, i: ()}, tr: False1::default(), fa: Id::default(), i: ()},
+                }
+            }
+        }
+
+        // `∃odd{(< k)}`
+        // `\(x: bool) = if k < min + 2 {if k == min {false_1(x)} else {not(x)}} else {true_1(x)}`
+        impl ExPath for Odd<$t, LtK<$t>> {
+            type Lift = IfK<$t, LtK<$t>, IfK<$t, EqK<$t>, False1<bool>, Not>, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {
+                    k: self.i.k, co: LtK {k: $t::MIN + 2, i: ()}, fa: (), i: (),
+                    tr: IfK {k: self.i.k, co: EqK {k: $t::MIN, i: ()}, tr: False1::default(), fa: Not::default(), i: ()},
+                }
+            }
+        }
+
+        // `∃(= k){(= k2)}`
+        // `\(x: bool) = if k == k2 {id(x)} else {not(x)}`
+        impl ExPath for EqK<$t, EqK<$t>> {
+            type Lift = IfK<$t, EqK<$t>, Id<bool>, Not>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.i.k, co: EqK {k: self.k, i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+            }
+        }
+
+        // `∃(< k){(= k2)}`
+        // `\(x: bool) = if k2 < k {id(x)} else {not(x)}`
+        impl ExPath for LtK<$t, EqK<$t>> {
+            type Lift = IfK<$t, LtK<$t>, Id<bool>, Not>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.i.k, co: LtK {k: self.k, i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+            }
+        }
+
+        // `∃(>= k){(= k2)}`
+        // `\(x: bool) = if k2 >= k {id(x)} else {not(x)}`
+        impl ExPath for GeK<$t, EqK<$t>> {
+            type Lift = IfK<$t, GeK<$t>, Id<bool>, Not>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.i.k, co: GeK {k: self.k, i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+            }
+        }
+
+        // `∃(= k){(>= k2)}`
+        // `\(x: bool) = if k < k2 {not(x)} else {if k2 == max {if k == max {id(x)} else {true_1}} else {true_1}}`
+        impl ExPath for EqK<$t, GeK<$t>> {
+            type Lift = IfK<$t, LtK<$t>, Not, IfK<$t, EqK<$t>, IfK<$t, EqK<$t>, Id<bool>, ()>, ()>>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {
+                    k: self.k, co: LtK {k: self.i.k, i: ()}, tr: Not::default(), i: (),
+                    fa: IfK {
+                        k: self.i.k, co: EqK {k: $t::MAX, i: ()}, fa: (), i: (),
+                        tr: IfK {k: self.k, co: EqK {k: $t::MAX, i: ()}, tr: Id::default(), fa: (), i: ()},
+                    },
+                }
+            }
+        }
+
+        // `∃(< k){(>= k2)}`
+        // `max` is never less than `k`, so `false` is always returned for some input.
+        // `\(x: bool) = if k2 >= k {not(x)} else {true_1(x)}`
+        impl ExPath for LtK<$t, GeK<$t>> {
+            type Lift = IfK<$t, GeK<$t>, Not, ()>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.i.k, co: GeK {k: self.k, i: ()}, tr: Not::default(), fa: (), i: ()}
+            }
+        }
+
+        // `∃(>= k){(>= k2)}`
+        // `\(x: bool) = if k2 < k {true_1(x)} else {id(x)}`
+        impl ExPath for GeK<$t, GeK<$t>> {
+            type Lift = IfK<$t, LtK<$t>, (), Id<bool>>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {k: self.i.k, co: LtK {k: self.k, i: ()}, tr: (), fa: Id::default(), i: ()}
+            }
+        }
+
+        // `∃(= k){(< k2)}`
+        // `\(x: bool) = if k2 == min {false_1(x)} else {if k < k2 {if k2 == min + 1 {id(x)} else {true_1(x)}} else {not(x)}}`
+        impl ExPath for EqK<$t, LtK<$t>> {
+            type Lift = IfK<$t, EqK<$t>, False1<bool>, IfK<$t, LtK<$t>, IfK<$t, EqK<$t>, Id<bool>, ()>, Not>>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {
+                    k: self.i.k, co: EqK {k: $t::MIN, i: ()}, i: (),
+                    tr: False1::default(),
+                    fa: IfK {
+                        k: self.k, co: LtK {k: self.i.k, i: ()}, fa: Not::default(), i: (),
+                        tr: IfK {k: self.i.k, co: EqK {k: $t::MIN + 1, i: ()}, tr: Id::default(), fa: (), i: ()},
+                    },
+                }
+            }
+        }
+
+        // `∃(< k){(< k2)}`
+        // `\(x: bool) = if k2 == min {false_1(x)} else {if k == min {not(x)} else {if k >= k2 {id(x)} else {true_1(x)}}}`
+        impl ExPath for LtK<$t, LtK<$t>> {
+            type Lift = IfK<$t, EqK<$t>, False1<bool>, IfK<$t, EqK<$t>, Not, IfK<$t, GeK<$t>, Id<bool>, ()>>>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {
+                    k: self.i.k, co: EqK {k: $t::MIN, i: ()}, i: (),
+                    tr: False1::default(),
+                    fa: IfK {
+                        k: self.k, co: EqK {k: $t::MIN, i: ()}, tr: Not::default(), i: (),
+                        fa: IfK {k: self.k, co: GeK {k: self.i.k, i: ()}, tr: Id::default(), fa: (), i: ()},
+                    },
+                }
+            }
+        }
+
+        // `∃(>= k){(< k2)}`
+        // `\(x: bool) = if k2 == min {false_1(x)} else {if k == min {id(x)} else {if k < k2 {true_1(x)} else {not(x)}}}`
+        impl ExPath for GeK<$t, LtK<$t>> {
+            type Lift = IfK<$t, EqK<$t>, False1<bool>, IfK<$t, EqK<$t>, Id<bool>, IfK<$t, LtK<$t>, (), Not>>>;
+            fn ex_path(&self) -> Self::Lift {
+                IfK {
+                    k: self.i.k, co: EqK {k: $t::MIN, i: ()}, tr: False1::default(), i: (),
+                    fa: IfK {
+                        k: self.k, co: EqK {k: $t::MIN, i: ()}, tr: Id::default(), i: (),
+                        fa: IfK {k: self.k, co: LtK {k: self.i.k, i: ()}, tr: (), fa: Not::default(), i: ()},
+                    },
+                }
+            }
+        }
+    };
+}
+
+cmp_impl!{i8}
+cmp_impl!{i16}
+cmp_impl!{i32}
+cmp_impl!{i64}
@@ -13,6 +13,13 @@ impl ExPath for () {
     fn ex_path(&self) -> Self::Lift {Id::default()}
 }
 
+// `∃(g0, g1) <=> (∃g0, ∃g1)`
+impl<A: ExPath, B: ExPath> ExPath for (A, B) {
+    type Lift = (A::Lift, B::Lift);
+
+    fn ex_path(&self) -> Self::Lift {(self.0.ex_path(), self.1.ex_path())}
+}
+
 // When the same function are in both branches of an `if` expression,
 // the condition is irrelevant.
 impl<C, T, I: Clone> ExPath for If<C, T, T, I>
@@ -27,17 +34,102 @@ impl<C, T, I: Clone> ExPath for If<C, T, T, I>
 
 // Take the existential path of the branches.
 // This makes the `IfK` object map back to itself nicely.
-impl<T: Clone, C: Clone, A: ExPath, B: ExPath> ExPath for IfK<T, C, A, B> {
-    type Lift = IfK<T, C, A::Lift, B::Lift>;
+// `∃if_k(k, co, tr, fa){i} <=> if_k(k, co, ∃tr{i}, ∃fa{i})`
+impl<T: Clone, C: Clone, A, B, I: Clone> ExPath for IfK<T, C, A, B, I>
+    where A: Constrain<I>, A::Lift: ExPath, B: Constrain<I>, B::Lift: ExPath
+{
+    type Lift = IfK<T, C, <A::Lift as ExPath>::Lift, <B::Lift as ExPath>::Lift>;
 
     fn ex_path(&self) -> Self::Lift {
         IfK {
             k: self.k.clone(), co: self.co.clone(),
-            tr: self.tr.ex_path(), fa: self.fa.ex_path(), i: ()
+            tr: self.tr.i_force(self.i.clone()).ex_path(),
+            fa: self.fa.i_force(self.i.clone()).ex_path(), i: ()
+        }
+    }
+}
+
+// `∃id{if_k(k, co, tr, fa)} <=> if_k(k, co, ∃id{tr}, ∃id{fa})`
+// `∃not{if_k(k, co, tr, fa)} <=> if_k(k, co, ∃not{tr}, ∃not{fa})`
+// The branches are listed to avoid overflow when the type is not known.
+macro_rules! bool_if_k_impl {
+    ($a:ty) => {
+        bool_if_k_impl!{$a, ()}
+        bool_if_k_impl!{$a, Id<bool>}
+        bool_if_k_impl!{$a, Not}
+    };
+    ($a:ty , $b:ty) => {
+        impl<T: Clone, C: Clone> ExPath for Id<bool, IfK<T, C, $a, $b>> {
+            type Lift = IfK<T, C, <Id<bool, $a> as ExPath>::Lift, <Id<bool, $b> as ExPath>::Lift>;
+
+            fn ex_path(&self) -> Self::Lift {
+                IfK {
+                    k: self.i.k.clone(), co: self.i.co.clone(),
+                    tr: Id::<bool>::default().i_force(self.i.tr).ex_path(),
+                    fa: Id::<bool>::default().i_force(self.i.fa).ex_path(), i: ()
+                }
+            }
+        }
+
+        impl<T: Clone, C: Clone> ExPath for Not<IfK<T, C, $a, $b>> {
+            type Lift = IfK<T, C, <Not<$a> as ExPath>::Lift, <Not<$b> as ExPath>::Lift>;
+
+            fn ex_path(&self) -> Self::Lift {
+                IfK {
+                    k: self.i.k.clone(), co: self.i.co.clone(),
+                    tr: Not::<()>::default().i_force(self.i.tr).ex_path(),
+                    fa: Not::<()>::default().i_force(self.i.fa).ex_path(), i: ()
+                }
+            }
+        }
+    };
+}
+
+bool_if_k_impl!{()}
+bool_if_k_impl!{Id<bool>}
+bool_if_k_impl!{Not}
+
+// `∃union(if(co, tr, fa)){i} <=> ∃tr{co ∧ i} ∨ ∃fa{¬co ∧ i}`
+// This works for any `If` built from dictionary parts.
+// Some combinations have an `ExPath` for `If` with a simpler result.
+impl<Co, Tr, Fa, I> ExPath for Union<If<Co, Tr, Fa, I>>
+    where
+        Co: Clone + Negate,
+        // `co ∧ i`
+        I: Intersect<Co> + Intersect<Co::Lift>,
+        // `∃tr{co ∧ i}`
+        Tr: Constrain<<I as Intersect<Co>>::Lift>,
+        <Tr as Constrain<<I as Intersect<Co>>::Lift>>::Lift: ExPath,
+        // `∃fa{¬co ∧ i}`
+        Fa: Constrain<<I as Intersect<Co::Lift>>::Lift>,
+        <Fa as Constrain<<I as Intersect<Co::Lift>>::Lift>>::Lift: ExPath,
+{
+    type Lift = Either<
+        <<Tr as Constrain<<I as Intersect<Co>>::Lift>>::Lift as ExPath>::Lift,
+        <<Fa as Constrain<<I as Intersect<Co::Lift>>::Lift>>::Lift as ExPath>::Lift
+    >;
+
+    fn ex_path(&self) -> Self::Lift {
+        let f = &self.f;
+        Either {
+            a: f.tr.i_force(f.i.intersect(f.co.clone())).ex_path(),
+            b: f.fa.i_force(f.i.intersect(f.co.negate())).ex_path(),
+            i: (),
         }
     }
 }
 
+// `∃(g . f) <=> ∃g{∃f}`
+impl<G, F> ExPath for Compose<G, F>
+    where F: ExPath, G: Constrain<F::Lift>, G::Lift: ExPath
+{
+    type Lift = <<G as Constrain<F::Lift>>::Lift as ExPath>::Lift;
+
+    fn ex_path(&self) -> Self::Lift {
+        self.g.i(self.f.ex_path()).ex_path()
+    }
+}
+
 macro_rules! ex_impl {
     // Apply transformation to a tuple with two functions.
     ($a:ident < $at:ident , ( $t:ty , $u:ty ) > ) => {
@@ -73,7 +165,7 @@ macro_rules! ex_impl {
         impl<T> ExPath for $a {
             type Lift = ();
 
-            fn ex_path(&self) -> () {()}
+            fn ex_path(&self) -> Self::Lift {}
         }
     };
     (T $a:ty , $b:ident) => {
@@ -97,6 +189,34 @@ macro_rules! ex_impl {
             fn ex_path(&self) -> $b {$b::default()}
         }
     };
+    (Nat K $a:ty , $b:ident < $bt:ident >) => {
+        impl<T: Nat> ExPath for $a {
+            type Lift = $b<$bt>;
+
+            fn ex_path(&self) -> $b<$bt> {$b {k: self.k, i: ()}}
+        }
+    };
+    (Nat $a:ty , ()) => {
+        impl<T: Nat> ExPath for $a {
+            type Lift = ();
+
+            fn ex_path(&self) -> Self::Lift {}
+        }
+    };
+    (Nat $a:ty , $b:ident) => {
+        impl<T: Nat> ExPath for $a {
+            type Lift = $b;
+
+            fn ex_path(&self) -> $b {$b::default()}
+        }
+    };
+    (Nat $a:ty , $b:ident < $bt:ident >) => {
+        impl<T: Nat> ExPath for $a {
+            type Lift = $b<$bt>;
+
+            fn ex_path(&self) -> $b<$bt> {$b::<$bt>::default()}
+        }
+    };
     (K $a:ty , $b:ident < $bt:ident >) => {
         impl ExPath for $a {
             type Lift = $b<$bt>;
@@ -122,7 +242,7 @@ macro_rules! ex_impl {
         impl ExPath for $a {
             type Lift = ();
 
-            fn ex_path(&self) -> () {()}
+            fn ex_path(&self) -> Self::Lift {}
         }
     };
 }
@@ -139,9 +259,10 @@ ex_impl!{Not<(Id<bool>, Id<bool>)>}
 ex_impl!{Not<((), ())>}
 ex_impl!{T Id<T>, ()}
 ex_impl!{Id<bool, Not>, Not}
+ex_impl!{Id<bool, Id<bool>>, Id<bool>}
 ex_impl!{T U False1<T, U>, Not}
 ex_impl!{And, ()}
-ex_impl!{And<((), ())>}
+ex_impl!{And<((), ())>, ()}
 ex_impl!{And<Not>, Not}
 ex_impl!{And<Id<bool>>, Id<bool>}
 ex_impl!{And<(Not, ())>, Not}
@@ -153,7 +274,7 @@ ex_impl!{And<(Id<bool>, Id<bool>)>, Id<bool>}
 ex_impl!{And<(Not, Id<bool>)>, Not}
 ex_impl!{And<(Id<bool>, Not)>, Not}
 ex_impl!{Or, ()}
-ex_impl!{Or<((), ())>}
+ex_impl!{Or<((), ())>, ()}
 ex_impl!{Or<Not>, Not}
 ex_impl!{Or<Id<bool>>, Id<bool>}
 ex_impl!{Or<(Not, ())>, ()}
@@ -165,7 +286,7 @@ ex_impl!{Or<(Id<bool>, Id<bool>)>, Id<bool>}
 ex_impl!{Or<(Not, Id<bool>)>, Id<bool>}
 ex_impl!{Or<(Id<bool>, Not)>, Id<bool>}
 ex_impl!{T Eq<T>, ()}
-ex_impl!{Eq<bool, ((), ())>}
+ex_impl!{Eq<bool, ((), ())>, ()}
 ex_impl!{Eq<bool, Not>, Id<bool>}
 ex_impl!{Eq<bool, Id<bool>>, Id<bool>}
 ex_impl!{Eq<bool, (Not, ())>, ()}
@@ -177,8 +298,21 @@ ex_impl!{Eq<bool, (Id<bool>, Id<bool>)>, Id<bool>}
 ex_impl!{Eq<bool, (Not, Id<bool>)>, Not}
 ex_impl!{Eq<bool, (Id<bool>, Not)>, Not}
 ex_impl!{T EqK<T>, ()}
+ex_impl!{T Neq<T>, ()}
+ex_impl!{Neq<bool, ((), ())>, ()}
+ex_impl!{Neq<bool, Id<bool>>, Not}
+ex_impl!{Neq<bool, Not>, Not}
+ex_impl!{Neq<bool, (Not, ())>, ()}
+ex_impl!{Neq<bool, ((), Not)>, ()}
+ex_impl!{Neq<bool, (Not, Not)>, Not}
+ex_impl!{Neq<bool, (Id<bool>, ())>, ()}
+ex_impl!{Neq<bool, ((), Id<bool>)>, ()}
+ex_impl!{Neq<bool, (Id<bool>, Id<bool>)>, Not}
+ex_impl!{Neq<bool, (Not, Id<bool>)>, Id<bool>}
+ex_impl!{Neq<bool, (Id<bool>, Not)>, Id<bool>}
+ex_impl!{T NeqK<T>, ()}
 ex_impl!{Xor, ()}
-ex_impl!{Xor<((), ())>}
+ex_impl!{Xor<((), ())>, ()}
 ex_impl!{Xor<Id<bool>>, Not}
 ex_impl!{Xor<Not>, Not}
 ex_impl!{Xor<(Not, ())>, ()}
@@ -190,7 +324,7 @@ ex_impl!{Xor<(Id<bool>, Id<bool>)>, Not}
 ex_impl!{Xor<(Not, Id<bool>)>, Id<bool>}
 ex_impl!{Xor<(Id<bool>, Not)>, Id<bool>}
 ex_impl!{Nand, ()}
-ex_impl!{Nand<((), ())>}
+ex_impl!{Nand<((), ())>, ()}
 ex_impl!{Nand<Not>, Id<bool>}
 ex_impl!{Nand<Id<bool>>, Not}
 ex_impl!{Nand<((), Not)>, Id<bool>}
@@ -262,6 +396,13 @@ ex_impl!{Nexc<((), Id<bool>)>, Id<bool>}
 ex_impl!{Nexc<(Not, Id<bool>)>, Id<bool>}
 ex_impl!{Nexc<(Id<bool>, Not)>, Not}
 ex_impl!{T Add<T>, ()}
+ex_impl!{T WrappingAdd<T>, ()}
+ex_impl!{T CheckedAdd<T>, ()}
+ex_impl!{T SaturatingAdd<T>, ()}
+ex_impl!{T Mul<T>, ()}
+ex_impl!{T Sub<T>, ()}
+ex_impl!{T Div<T>, ()}
+ex_impl!{T Ge<T>, ()}
 ex_impl!{T Even<T>, ()}
 ex_impl!{T Even<T, Even<T>>, Id<bool>}
 ex_impl!{T Even<T, Odd<T>>, Not}
@@ -272,28 +413,179 @@ ex_impl!{T Even<T, (Odd<T>, Odd<T>)>}
 ex_impl!{T Odd<T>, ()}
 ex_impl!{T Odd<T, Odd<T>>, Id<bool>}
 ex_impl!{T Odd<T, Even<T>>, Not}
+ex_impl!{T Odd<T, (Even<T>, Odd<T>)>}
+ex_impl!{T Odd<T, (Odd<T>, Even<T>)>}
+ex_impl!{T Odd<T, (Even<T>, Even<T>)>}
+ex_impl!{T Odd<T, (Odd<T>, Odd<T>)>}
 
 #[macro_use]
 mod macros;
 mod nat;
+mod int;
+mod float;
+mod big_nat;
+mod consts;
 
-macro_rules! nat_nat_impl {
+macro_rules! ge_impl {
+    ($g:ident , $i:ty) => {
+        impl<T: Nat> ExPath for Even<T, $g<T, $i>> {
+            type Lift = <Even<T, $i> as ExPath>::Lift;
+            fn ex_path(&self) -> Self::Lift {
+                Even::<T>::default().i_force(self.i.i.clone()).ex_path()
+            }
+        }
+
+        impl<T: Nat> ExPath for Odd<T, $g<T, $i>> {
+            type Lift = <Odd<T, $i> as ExPath>::Lift;
+            fn ex_path(&self) -> Self::Lift {
+                Odd::<T>::default().i_force(self.i.i.clone()).ex_path()
+            }
+        }
+    };
+}
+
+ex_impl!{Nat Add<T, (Even<T>, Odd<T>)>, Odd<T>}
+ex_impl!{Nat Add<T, (Odd<T>, Even<T>)>, Odd<T>}
+ex_impl!{Nat Add<T, (Even<T>, Even<T>)>, Even<T>}
+
+// `∃add{(odd, odd)} => if((< 2), false_1, even)`
+impl<T: Nat> ExPath for Add<T, (Odd<T>, Odd<T>)> {
+    type Lift = If<LtK<T>, False1<T>, Even<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        If {co: LtK {k: T::from(2), i: ()}, tr: False1::default(), fa: Even::default(), i: ()}
+    }
+}
+
+// The product of an even and an odd number is even, e.g. `2 * 1 = 2`.
+// `∃mul{(even, even)}` returns only multiples of 4, which is `(% 4 = 0)`.
+ex_impl!{Nat Mul<T, (Even<T>, Odd<T>)>, Even<T>}
+ex_impl!{Nat Mul<T, (Odd<T>, Even<T>)>, Even<T>}
+ex_impl!{Nat Mul<T, (Odd<T>, Odd<T>)>, Odd<T>}
+ex_impl!{Nat Mul<T, Odd<T>>, Odd<T>}
+
+// `\(a, b) = a % b` is less than `b`, which is at most `max`.
+impl<T: Nat> ExPath for Rem<T> {
+    type Lift = LtK<T>;
+    fn ex_path(&self) -> Self::Lift {LtK {k: T::MAX, i: ()}}
+}
+
+// The difference of two numbers with same parity is even, e.g. `x - x = 0`.
+ex_impl!{Nat Sub<T, (Even<T>, Even<T>)>, Even<T>}
+ex_impl!{Nat Sub<T, (Odd<T>, Odd<T>)>, Even<T>}
+ex_impl!{Nat Sub<T, Even<T>>, Even<T>}
+ex_impl!{Nat Sub<T, Odd<T>>, Even<T>}
+// `max - 0 = max`
+ex_impl!{Nat Sub<T, (Odd<T>, Even<T>)>, Odd<T>}
+
+// `∃sub{(even, odd)} => if((< max), odd, false_1)`
+// The largest even number is `max - 1`.
+impl<T: Nat> ExPath for Sub<T, (Even<T>, Odd<T>)> {
+    type Lift = If<LtK<T>, Odd<T>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        If {co: LtK {k: T::MAX, i: ()}, tr: Odd::default(), fa: False1::default(), i: ()}
+    }
+}
+
+// `∃even{ge{(a, b)}} => ∃even{(a, b)}`
+// For parity constraints, every argument is used by some input where `a >= b`.
+ge_impl!{Ge, ()}
+ge_impl!{Ge, Even<T>}
+ge_impl!{Ge, Odd<T>}
+ge_impl!{Ge, (Even<T>, Even<T>)}
+ge_impl!{Ge, (Odd<T>, Odd<T>)}
+ge_impl!{Ge, (Even<T>, Odd<T>)}
+ge_impl!{Ge, (Odd<T>, Even<T>)}
+
+// `∃even{add_fits{(a, b)}} => ∃even{(a, b)}`
+// Every argument except `max` is used by some input where `a + b <= max`, e.g. `a + 1`.
+// There are both even and odd numbers below `max`.
+ge_impl!{AddFits, ()}
+ge_impl!{AddFits, Even<T>}
+ge_impl!{AddFits, Odd<T>}
+ge_impl!{AddFits, (Even<T>, Even<T>)}
+ge_impl!{AddFits, (Odd<T>, Odd<T>)}
+ge_impl!{AddFits, (Even<T>, Odd<T>)}
+ge_impl!{AddFits, (Odd<T>, Even<T>)}
+
+// `∃rem(k){add_fits} => ∃rem(k)`, since every argument is used by some input with `b = 0`.
+impl<T: Nat> ExPath for RemK<T, AddFits<T>> {
+    type Lift = <RemK<T> as ExPath>::Lift;
+    fn ex_path(&self) -> Self::Lift {RemK {k: self.k, i: ()}.ex_path()}
+}
+
+// Wrapping addition preserves parity, since `max + 1 = 0` is even.
+// This also makes `0` the sum of two odd numbers.
+ex_impl!{Nat WrappingAdd<T, (Even<T>, Odd<T>)>, Odd<T>}
+ex_impl!{Nat WrappingAdd<T, (Odd<T>, Even<T>)>, Odd<T>}
+ex_impl!{Nat WrappingAdd<T, (Even<T>, Even<T>)>, Even<T>}
+ex_impl!{Nat WrappingAdd<T, (Odd<T>, Odd<T>)>, Even<T>}
+
+// Checked addition has the same image as `add`.
+ex_impl!{Nat CheckedAdd<T, (Even<T>, Odd<T>)>, Odd<T>}
+ex_impl!{Nat CheckedAdd<T, (Odd<T>, Even<T>)>, Odd<T>}
+ex_impl!{Nat CheckedAdd<T, (Even<T>, Even<T>)>, Even<T>}
+
+// `∃checked_add{(odd, odd)} => if((< 2), false_1, even)`
+impl<T: Nat> ExPath for CheckedAdd<T, (Odd<T>, Odd<T>)> {
+    type Lift = If<LtK<T>, False1<T>, Even<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        If {co: LtK {k: T::from(2), i: ()}, tr: False1::default(), fa: Even::default(), i: ()}
+    }
+}
+
+// Saturating addition returns `max` on overflow, which is odd.
+ex_impl!{Nat SaturatingAdd<T, (Even<T>, Odd<T>)>, Odd<T>}
+ex_impl!{Nat SaturatingAdd<T, (Odd<T>, Even<T>)>, Odd<T>}
+
+// `∃saturating_add{(even, even)} => if((< max), even, true_1)`
+impl<T: Nat> ExPath for SaturatingAdd<T, (Even<T>, Even<T>)> {
+    type Lift = If<LtK<T>, Even<T>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        If {co: LtK {k: T::MAX, i: ()}, tr: Even::default(), fa: (), i: ()}
+    }
+}
+
+// `∃saturating_add{(odd, odd)} => if((< max), if((< 2), false_1, even), true_1)`
+impl<T: Nat> ExPath for SaturatingAdd<T, (Odd<T>, Odd<T>)> {
+    type Lift = If<LtK<T>, If<LtK<T>, False1<T>, Even<T>>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        If {
+            co: LtK {k: T::MAX, i: ()}, fa: (), i: (),
+            tr: If {co: LtK {k: T::from(2), i: ()}, tr: False1::default(), fa: Even::default(), i: ()}
+        }
+    }
+}
+ex_impl!{Nat If<LtK<T>, Even<T>, ()>, ()}
+ex_impl!{Nat If<LtK<T>, If<LtK<T>, False1<T>, Even<T>>, ()>, ()}
+
+// Under every parity constraint, some small sum fits and some sum with `max - 1` or `max` overflows.
+ex_impl!{Nat AddFits<T>, ()}
+ex_impl!{Nat AddFits<T, (Even<T>, Odd<T>)>, ()}
+ex_impl!{Nat AddFits<T, (Odd<T>, Even<T>)>, ()}
+ex_impl!{Nat AddFits<T, (Even<T>, Even<T>)>, ()}
+ex_impl!{Nat AddFits<T, (Odd<T>, Odd<T>)>, ()}
+
+// Signed integers have no lower bound at zero,
+// so every sum and product of the right parity is reached, e.g. `min = -1 + (min + 1)`.
+macro_rules! int_int_impl {
     ($t:ident) => {
         ex_impl!{Add<$t, (Even<$t>, Odd<$t>)>, Odd<$t>}
         ex_impl!{Add<$t, (Odd<$t>, Even<$t>)>, Odd<$t>}
         ex_impl!{Add<$t, (Even<$t>, Even<$t>)>, Even<$t>}
+        ex_impl!{Add<$t, (Odd<$t>, Odd<$t>)>, Even<$t>}
 
-        // `∃add{(odd, odd)} => if((< 2), false_1, even)`
-        impl ExPath for Add<$t, (Odd<$t>, Odd<$t>)> {
-            type Lift = If<LtK<$t>, False1<$t>, Even<$t>>;
-            fn ex_path(&self) -> Self::Lift {
-                If {co: LtK {k: 2, i: ()}, tr: False1::default(), fa: Even::default(), i: ()}
-            }
-        }
+        ex_impl!{Mul<$t, (Even<$t>, Odd<$t>)>, Even<$t>}
+        ex_impl!{Mul<$t, (Odd<$t>, Even<$t>)>, Even<$t>}
+        ex_impl!{Mul<$t, (Odd<$t>, Odd<$t>)>, Odd<$t>}
+        ex_impl!{Mul<$t, Odd<$t>>, Odd<$t>}
+
+        // Every argument is used by some input where the sum fits, e.g. `a + 0`.
+        ex_impl!{Even<$t, AddFits<$t>>, ()}
+        ex_impl!{Odd<$t, AddFits<$t>>, ()}
     }
 }
 
-nat_nat_impl!{u8}
-nat_nat_impl!{u16}
-nat_nat_impl!{u32}
-nat_nat_impl!{u64}
+int_int_impl!{i8}
+int_int_impl!{i16}
+int_int_impl!{i32}
+int_int_impl!{i64}
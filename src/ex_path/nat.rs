@@ -1,570 +1,1911 @@
 use *;
 
-macro_rules! nat_impl {
-    ($t:ident , max : $($max:tt)*) => {
-        ex_impl!{K AddK<$t>, GeK<$t>}
-        ex_impl!{If<GeK<$t>, Even<$t>, Odd<$t>>, ()}
-        ex_impl!{If<GeK<$t>, Odd<$t>, Even<$t>>, ()}
-        ex_impl!{If<LtK<$t>, Even<$t>, Odd<$t>>, ()}
-        ex_impl!{If<LtK<$t>, Odd<$t>, Even<$t>>, ()}
-        ex_impl!{If<GeK<$t>, IfK<$t, Odd<$t>, Even<$t>, Odd<$t>>, False1<$t>>, ()}
-        // `∃if((< k), false_1, even)`
-        // `k == 0 => ∃if((< 0), false_1, even)`
-        // `          ∃if(false, false_1, even)`
-        // `          ∃even`
-        // `          true_1`
-        // `\(x: bool) = if k == 0 {true_1} else {true_1}`
-        ex_impl!{If<LtK<$t>, False1<$t>, Even<$t>>, ()}
-        // `∃even{if((< k), false_1, even)} => id`
-        ex_impl!{Even<$t, If<LtK<$t>, False1<$t>, Even<$t>>>, Id<bool>}
-
-        // `∃even{(= k)}`
-        // `\(x) = if even(k) {id(x)} else {not(x)}`
-        impl ExPath for Even<$t, EqK<$t>> {
-            type Lift = IfK<$t, Even<$t>, Id<bool>, Not>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()}
-            }
+ex_impl!{Nat K AddK<T>, GeK<T>}
+ex_impl!{Nat If<GeK<T>, Even<T>, Odd<T>>, ()}
+ex_impl!{Nat If<GeK<T>, Odd<T>, Even<T>>, ()}
+ex_impl!{Nat If<LtK<T>, Even<T>, Odd<T>>, ()}
+ex_impl!{Nat If<LtK<T>, Odd<T>, Even<T>>, ()}
+
+// `∃if((< k), false_1, even)`
+// `k == 0 => ∃if((< 0), false_1, even)`
+// `          ∃if(false, false_1, even)`
+// `          ∃even`
+// `          true_1`
+// `k == max => ∃if((< max), false_1, even)`
+// `            ∃false_1`
+// `            not`
+// `\(x: bool) = if k == max {not(x)} else {true_1(x)}`
+impl<T: Nat> ExPath for If<LtK<T>, False1<T>, Even<T>> {
+    type Lift = IfK<T, EqK<T>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.co.k, co: EqK {k: T::MAX, i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+// `∃even{if((< k), false_1, even)} => id`
+// The constraint is empty when `k == max`,
+// which does not happen for `∃add{(odd, odd)} => if((< 2), false_1, even)`.
+ex_impl!{Nat Even<T, If<LtK<T>, False1<T>, Even<T>>>, Id<bool>}
+
+// `∃if((< k), odd, false_1)`
+// `\(x: bool) = if k < 2 {not(x)} else {true_1(x)}`
+impl<T: Nat> ExPath for If<LtK<T>, Odd<T>, False1<T>> {
+    type Lift = IfK<T, LtK<T>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.co.k, co: LtK {k: T::from(2), i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+// `∃even{if((< k), odd, false_1)} => not`
+// The constraint is empty when `k < 2`,
+// which does not happen for `∃sub{(even, odd)} => if((< max), odd, false_1)`.
+ex_impl!{Nat Even<T, If<LtK<T>, Odd<T>, False1<T>>>, Not}
+
+// `∃sub(k)`
+// `\(x: nat) = if k == 0 {true_1(x)} else {(< max - k + 1)(x)}`
+// The `(< max - k + 1)` branch is not used when it wraps around.
+impl<T: Nat> ExPath for SubK<T> {
+    type Lift = IfK<T, EqK<T>, (), LtK<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: (), i: (),
+            fa: LtK {k: (T::MAX - self.k).wrapping_add(T::ONE), i: ()}
         }
+    }
+}
 
-        impl ExPath for GeK<$t> {
-            type Lift = IfK<$t, EqK<$t>, Id<bool>, ()>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.k, co: EqK {k: 0, i: ()}, tr: Id::default(), fa: (), i: ()}
+// `∃sub(k){even}`
+// The largest even number is `max - 1`.
+// `\(x: nat) = if x < max - k {if even(k) {even(x)} else {odd(x)}} else {false}`
+impl<T: Nat> ExPath for SubK<T, Even<T>> {
+    type Lift = If<LtK<T>, IfK<T, Even<T>, Even<T>, Odd<T>>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        If {
+            co: LtK {k: T::MAX - self.k, i: ()},
+            tr: IfK {k: self.k, co: Even::default(), tr: Even::default(), fa: Odd::default(), i: ()},
+            fa: False1::default(), i: ()
+        }
+    }
+}
+
+// `∃sub(k){odd}`
+// `\(x: nat) = if k == 0 {odd(x)} else {if x < max - k + 1 {if odd(k) {even(x)} else {odd(x)}} else {false}}`
+impl<T: Nat> ExPath for SubK<T, Odd<T>> {
+    type Lift = IfK<T, EqK<T>, Odd<T>, If<LtK<T>, IfK<T, Odd<T>, Even<T>, Odd<T>>, False1<T>>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Odd::default(), i: (),
+            fa: If {
+                co: LtK {k: (T::MAX - self.k).wrapping_add(T::ONE), i: ()},
+                tr: IfK {k: self.k, co: Odd::default(), tr: Even::default(), fa: Odd::default(), i: ()},
+                fa: False1::default(), i: ()
             }
         }
+    }
+}
 
-        impl ExPath for LtK<$t> {
-            type Lift = IfK<$t, EqK<$t>, Not, ()>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.k, co: EqK {k: 0, i: ()}, tr: Not::default(), fa: (), i: ()}
+// `∃sub(k){(= k2)}`
+// `\(x: nat) = if k2 >= k {(= k2 - k)(x)} else {false_1(x)}`
+impl<T: Nat> ExPath for SubK<T, EqK<T>> {
+    type Lift = IfK<T, GeK<T>, EqK<T>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: GeK {k: self.k, i: ()}, i: (),
+            tr: EqK {k: self.i.k.wrapping_sub(self.k), i: ()},
+            fa: False1::default()
+        }
+    }
+}
+
+// `∃sub(k){(>= k2)}`
+// `\(x: nat) = if x >= k2 - k {(∃sub(k))(x)} else {false_1(x)}`
+impl<T: Nat> ExPath for SubK<T, GeK<T>> {
+    type Lift = If<GeK<T>, <SubK<T> as ExPath>::Lift, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        If {
+            co: GeK {k: self.i.k.saturating_sub(self.k), i: ()},
+            tr: SubK {k: self.k, i: ()}.ex_path(),
+            fa: False1::default(), i: ()
+        }
+    }
+}
+
+// `∃sub(k){(< k2)}`
+// `\(x: nat) = if k < k2 {(< k2 - k)(x)} else {false_1(x)}`
+impl<T: Nat> ExPath for SubK<T, LtK<T>> {
+    type Lift = IfK<T, LtK<T>, LtK<T>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: LtK {k: self.i.k, i: ()}, i: (),
+            tr: LtK {k: self.i.k.wrapping_sub(self.k), i: ()},
+            fa: False1::default()
+        }
+    }
+}
+
+// `∃div(k)`
+// `\(x: nat) = if k < 2 {if k == 0 {false_1(x)} else {true_1(x)}} else {(< max / k + 1)(x)}`
+// The `(< max / k + 1)` branch is not used for `k < 2`.
+impl<T: Nat> ExPath for DivK<T> {
+    type Lift = IfK<T, LtK<T>, IfK<T, EqK<T>, False1<T>, ()>, LtK<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: LtK {k: T::from(2), i: ()}, i: (),
+            tr: IfK {k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: False1::default(), fa: (), i: ()},
+            fa: LtK {k: T::MAX / self.k.max(T::from(2)) + T::ONE, i: ()}
+        }
+    }
+}
+
+// `∃div(k){(= k2)}`
+// `\(x: nat) = if k == 0 {false_1(x)} else {(= k2 / k)(x)}`
+impl<T: Nat> ExPath for DivK<T, EqK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<T>, EqK<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: False1::default(), i: (),
+            fa: EqK {k: self.i.k / self.k.max(T::ONE), i: ()}
+        }
+    }
+}
+
+// `∃div(k){(< k2)}`
+// `\(x: nat) = if k == 0 {false_1(x)} else {if k2 == 0 {false_1(x)} else {(< (k2 - 1) / k + 1)(x)}}`
+impl<T: Nat> ExPath for DivK<T, LtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<T>, IfK<T, EqK<T>, False1<T>, LtK<T>>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {
+                k: self.i.k, co: EqK {k: T::ZERO, i: ()}, tr: False1::default(), i: (),
+                fa: LtK {k: self.i.k.saturating_sub(T::ONE) / self.k.max(T::ONE) + T::ONE, i: ()}
             }
         }
+    }
+}
 
-        // `∃(>= k){even}`
-        // `\(x: bool) = if k == max {not(x)} else {true_1(x)}`
-        // The maxium value of an unsigned integer is an odd number.
-        // If `k` is set to this number, then there exists no greater even number.
-        // When that is the case, the `(>= k){even}` function always returns `false`.
-        impl ExPath for GeK<$t, Even<$t>> {
-            type Lift = IfK<$t, EqK<$t>, Not, ()>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.k, co: EqK {k: $($max)*, i: ()}, tr: Not::default(), fa: (), i: ()}
+// `∃div(k){(>= k2)}`
+// `\(x: nat) = if x >= k2 / k {(∃div(k))(x)} else {false_1(x)}`
+impl<T: Nat> ExPath for DivK<T, GeK<T>> {
+    type Lift = If<GeK<T>, <DivK<T> as ExPath>::Lift, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        If {
+            co: GeK {k: self.i.k / self.k.max(T::ONE), i: ()},
+            tr: DivK {k: self.k, i: ()}.ex_path(),
+            fa: False1::default(), i: ()
+        }
+    }
+}
+
+// `∃rem(k) => (< k)`
+// This is also correct for `k == 0`, since `(< 0)` is `false_1`.
+ex_impl!{Nat K RemK<T>, LtK<T>}
+
+// `∃rem(k){(= k2)}`
+// `\(x: nat) = if k == 0 {false_1(x)} else {(= k2 % k)(x)}`
+impl<T: Nat> ExPath for RemK<T, EqK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<T>, EqK<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: False1::default(), i: (),
+            fa: EqK {k: self.i.k % self.k.max(T::ONE), i: ()}
+        }
+    }
+}
+
+// `∃rem(k){(< k2)} => (< min(k, k2))`
+impl<T: Nat> ExPath for RemK<T, LtK<T>> {
+    type Lift = LtK<T>;
+    fn ex_path(&self) -> Self::Lift {
+        LtK {k: self.k.min(self.i.k), i: ()}
+    }
+}
+
+// `∃rem(k){(>= k2)}`
+// When `[k2, max]` contains at least `k` numbers, all remainders are returned.
+// Otherwise, the remainders go from `k2 % k` to `max % k` and might wrap around.
+// `\(x: nat) = if x >= a {(< b)(x)} else {(< c)(x)}`
+impl<T: Nat> ExPath for RemK<T, GeK<T>> {
+    type Lift = If<GeK<T>, LtK<T>, LtK<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        let (k, k2) = (self.k, self.i.k);
+        let (a, b, c) = if k == T::ZERO {(T::ZERO, T::ZERO, T::ZERO)}
+            else if T::MAX - k2 >= k - T::ONE {(T::ZERO, k, T::ZERO)}
+            else {
+                let (start, end) = (k2 % k, T::MAX % k);
+                if start <= end {(start, end + T::ONE, T::ZERO)} else {(start, k, end + T::ONE)}
+            };
+        If {co: GeK {k: a, i: ()}, tr: LtK {k: b, i: ()}, fa: LtK {k: c, i: ()}, i: ()}
+    }
+}
+
+// `∃add_mod(k) => (< k)`
+// This is also correct for `k == 0`, since `(< 0)` is `false_1`.
+ex_impl!{Nat K AddMod<T>, LtK<T>}
+
+// `∃add_mod(k){(< k2)} => (< min(k, 2 * k2 - 1))`
+// The sums `[0, 2 * k2 - 1)` cover all remainders when `2 * k2 - 1 >= k`.
+impl<T: Nat> ExPath for AddMod<T, LtK<T>> {
+    type Lift = LtK<T>;
+    fn ex_path(&self) -> Self::Lift {
+        LtK {k: self.k.min(self.i.k.saturating_sub(T::ONE).saturating_add(self.i.k)), i: ()}
+    }
+}
+
+// `∃(% m = r)`
+// `\(x: bool) = if (% m = r)(r) {if m == 1 {id(x)} else {true_1(x)}} else {not(x)}`
+// The residue `r` is reachable when `r < m` or `m == 0`.
+impl<T: Nat> ExPath for ModK<T> {
+    type Lift = IfK<T, ModK<T>, IfK<T, EqK<T>, Id<bool>, ()>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.r, co: ModK {m: self.m, r: self.r, i: ()}, fa: Not::default(), i: (),
+            tr: IfK {k: self.m, co: EqK {k: T::ONE, i: ()}, tr: Id::default(), fa: (), i: ()}
+        }
+    }
+}
+
+// `∃add(k){(% m = r)}`
+// `\(x: nat) = if (% m = r)(r) && r <= max - k {if x >= r + k {(% m = (r + k) % m)(x)} else {false_1(x)}} else {false_1(x)}`
+// The `r + k` constant saturates in the unused branch.
+impl<T: Nat> ExPath for AddK<T, ModK<T>> {
+    type Lift = IfK<T, ModK<T>, IfK<T, LeK<T>, If<GeK<T>, ModK<T>, False1<T>>, False1<T>>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        let (m, r, k) = (self.i.m, self.i.r, self.k);
+        let r2 = AddMod {k: m, i: ()}.eval((r, k)).unwrap_or(r.saturating_add(k));
+        IfK {
+            k: r, co: ModK {m, r, i: ()}, fa: False1::default(), i: (),
+            tr: IfK {
+                k: r, co: LeK {k: T::MAX - k, i: ()}, fa: False1::default(), i: (),
+                tr: If {
+                    co: GeK {k: r.saturating_add(k), i: ()},
+                    tr: ModK {m, r: r2, i: ()},
+                    fa: False1::default(), i: ()
+                }
             }
         }
+    }
+}
 
-        // `∃(>= k){odd}`
-        // `\(x: bool) = if k == max {id(x)} else {true_1(x)}`
-        // The maxium value of an unsigned integer is an odd number.
-        // When that is the case, the `(>= k){even}` function always returns `true`.
-        impl ExPath for GeK<$t, Odd<$t>> {
-            type Lift = IfK<$t, EqK<$t>, Id<bool>, ()>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.k, co: EqK {k: $($max)*, i: ()}, tr: Id::default(), fa: (), i: ()}
+// `∃add{(% m = r)}`
+// Both arguments have the same residue.
+// `\(x: nat) = if (% m = r)(r) && r <= max - r {if x >= 2 * r {(% m = (2 * r) % m)(x)} else {false_1(x)}} else {false_1(x)}`
+// The `2 * r` constant saturates in the unused branch.
+impl<T: Nat> ExPath for Add<T, ModK<T>> {
+    type Lift = IfK<T, ModK<T>, IfK<T, LeK<T>, If<GeK<T>, ModK<T>, False1<T>>, False1<T>>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        let (m, r) = (self.i.m, self.i.r);
+        let r2 = AddMod {k: m, i: ()}.eval((r, r)).unwrap_or(r.saturating_add(r));
+        IfK {
+            k: r, co: ModK {m, r, i: ()}, fa: False1::default(), i: (),
+            tr: IfK {
+                k: r, co: LeK {k: T::MAX - r, i: ()}, fa: False1::default(), i: (),
+                tr: If {
+                    co: GeK {k: r.saturating_add(r), i: ()},
+                    tr: ModK {m, r: r2, i: ()},
+                    fa: False1::default(), i: ()
+                }
             }
         }
+    }
+}
 
-        // `∃(< k){even}`
-        // `\(x: bool) = if k == 0 {not(x)} else {true_1(x)}`
-        impl ExPath for LtK<$t, Even<$t>> {
-            type Lift = IfK<$t, EqK<$t>, Not, ()>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.k, co: EqK {k: 0, i: ()}, tr: Not::default(), fa: (), i: ()}
+// `∃mul(k) => (% k = 0)`
+// This is also correct for `k == 0`, since `(% 0 = 0)` is `(= 0)`.
+impl<T: Nat> ExPath for MulK<T> {
+    type Lift = ModK<T>;
+    fn ex_path(&self) -> Self::Lift {
+        ModK {m: self.k, r: T::ZERO, i: ()}
+    }
+}
+
+// `∃mul(k){(% m = r)}`
+// `\(x: nat) = if (% m = r)(r) && r <= max / k {(% k * m = k * r)(x)} else {false_1(x)}`
+// When `k * m` overflows, only `k * r` is reachable, which is `(% 0 = k * r)`.
+// The `k * r` constant saturates in the unused branch.
+impl<T: Nat> ExPath for MulK<T, ModK<T>> {
+    type Lift = IfK<T, ModK<T>, IfK<T, LeK<T>, ModK<T>, False1<T>>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        let (m, r, k) = (self.i.m, self.i.r, self.k);
+        IfK {
+            k: r, co: ModK {m, r, i: ()}, fa: False1::default(), i: (),
+            tr: IfK {
+                k: r, co: LeK {k: T::MAX.checked_div(k).unwrap_or(T::MAX), i: ()},
+                fa: False1::default(), i: (),
+                tr: ModK {m: k.checked_mul(m).unwrap_or(T::ZERO), r: k.saturating_mul(r), i: ()}
             }
         }
+    }
+}
 
-        // `∃(< k){odd}`
-        // ```ignore
-        // k == 0 => ∃(< 0){odd} => ∃false_1{odd} ∃false_1 => not
-        // k == 1 => ∃(< 1){odd} => ∃false_1 => not
-        // k == 2 => ∃(< 2){odd} => true_1
-        // ```
-        // `\(x: bool) = if k < 2 {not(x)} else {true_1(x)}`
-        impl ExPath for LtK<$t, Odd<$t>> {
-            type Lift = IfK<$t, LtK<$t>, Not, ()>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.k, co: LtK {k: 0, i: ()}, tr: Not::default(), fa: (), i: ()}
+// There is no rule for `∃mul{(% m = r)}`,
+// since the products of two numbers in `(% m = r)` have no closed form for `r >= 2`.
+
+// `∃mul{(even, even)} => (% 4 = 0)`
+impl<T: Nat> ExPath for Mul<T, (Even<T>, Even<T>)> {
+    type Lift = ModK<T>;
+    fn ex_path(&self) -> Self::Lift {
+        ModK {m: T::from(4), r: T::ZERO, i: ()}
+    }
+}
+
+// `∃mul{even} => (% 4 = 0)`, since both arguments are even.
+impl<T: Nat> ExPath for Mul<T, Even<T>> {
+    type Lift = ModK<T>;
+    fn ex_path(&self) -> Self::Lift {
+        ModK {m: T::from(4), r: T::ZERO, i: ()}
+    }
+}
+
+// `∃even{(= k)}`
+// `\(x) = if even(k) {id(x)} else {not(x)}`
+impl<T: Nat> ExPath for Even<T, EqK<T>> {
+    type Lift = IfK<T, Even<T>, Id<bool>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()}
+    }
+}
+
+impl<T: Nat> ExPath for GeK<T> {
+    type Lift = IfK<T, EqK<T>, Id<bool>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Id::default(), fa: (), i: ()}
+    }
+}
+
+impl<T: Nat> ExPath for LtK<T> {
+    type Lift = IfK<T, EqK<T>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(>= k){even}`
+// `\(x: bool) = if k == 0 {id(x)} else {if k == max {not(x)} else {true_1(x)}}`
+// The maxium value of an unsigned integer is an odd number.
+// If `k` is set to this number, then there exists no greater even number.
+// When that is the case, the `(>= k){even}` function always returns `false`.
+impl<T: Nat> ExPath for GeK<T, Even<T>> {
+    type Lift = IfK<T, EqK<T>, Id<bool>, IfK<T, EqK<T>, Not, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Id::default(), i: (),
+            fa: IfK {k: self.k, co: EqK {k: T::MAX, i: ()}, tr: Not::default(), fa: (), i: ()}
+        }
+    }
+}
+
+// `∃(>= k){odd}`
+// `\(x: bool) = if k < 2 {id(x)} else {true_1(x)}`
+// The maxium value of an unsigned integer is an odd number,
+// so the `(>= k){odd}` function returns `true` for some input.
+// When `k < 2`, there is no odd number less than `k`.
+impl<T: Nat> ExPath for GeK<T, Odd<T>> {
+    type Lift = IfK<T, LtK<T>, Id<bool>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: LtK {k: T::from(2), i: ()}, tr: Id::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(< k){even}`
+// `\(x: bool) = if k == 0 {not(x)} else {if k == max {id(x)} else {true_1(x)}}`
+impl<T: Nat> ExPath for LtK<T, Even<T>> {
+    type Lift = IfK<T, EqK<T>, Not, IfK<T, EqK<T>, Id<bool>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Not::default(), i: (),
+            fa: IfK {k: self.k, co: EqK {k: T::MAX, i: ()}, tr: Id::default(), fa: (), i: ()}
+        }
+    }
+}
+
+// `∃(< k){odd}`
+// ```ignore
+// k == 0 => ∃(< 0){odd} => ∃false_1{odd} ∃false_1 => not
+// k == 1 => ∃(< 1){odd} => ∃false_1 => not
+// k == 2 => ∃(< 2){odd} => true_1
+// ```
+// `\(x: bool) = if k < 2 {not(x)} else {true_1(x)}`
+impl<T: Nat> ExPath for LtK<T, Odd<T>> {
+    type Lift = IfK<T, LtK<T>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: LtK {k: T::from(2), i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+
+impl<T: Nat> ExPath for EqK<T, Even<T>> {
+    type Lift = IfK<T, Even<T>, (), Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: Even::default(), tr: (), fa: Not::default(), i: ()}
+    }
+}
+
+impl<T: Nat> ExPath for EqK<T, Odd<T>> {
+    type Lift = IfK<T, Odd<T>, (), Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: Odd::default(), tr: (), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃add(k){even}`
+// `\(x: nat) = if x >= k {if even(k) {even(x)} else {odd(x)}} else {false}`
+impl<T: Nat> ExPath for AddK<T, Even<T>> {
+    type Lift = If<GeK<T>, IfK<T, Even<T>, Even<T>, Odd<T>>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        If {
+            co: GeK {k: self.k, i: ()},
+            tr: IfK {k: self.k, co: Even::default(), tr: Even::default(), fa: Odd::default(), i: ()},
+            fa: False1::default(), i: ()
+        }
+    }
+}
+
+// `∃add(k){odd}`
+// `\(x: nat) = if x >= k {if odd(k) {even(x)} else {odd(x)}} else {false}`
+impl<T: Nat> ExPath for AddK<T, Odd<T>> {
+    type Lift = If<GeK<T>, IfK<T, Odd<T>, Even<T>, Odd<T>>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        If {
+            co: GeK {k: self.k, i: ()},
+            tr: IfK {k: self.k, co: Odd::default(), tr: Even::default(), fa: Odd::default(), i: ()},
+            fa: False1::default(), i: ()
+        }
+    }
+}
+
+// `∃if((>= k), if even(k2) {even} else {odd}, false_1)`
+// `false` is returned for `x < k` or when `x` has the other parity.
+// The only number `>= max` is odd.
+// `\(x: bool) = if k == max {if even(k2) {not(x)} else {true_1(x)}} else {true_1(x)}`
+impl<T: Nat> ExPath for If<GeK<T>, IfK<T, Even<T>, Even<T>, Odd<T>>, False1<T>> {
+    type Lift = IfK<T, EqK<T>, IfK<T, Even<T>, Not, ()>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.co.k, co: EqK {k: T::MAX, i: ()}, i: (), fa: (),
+            tr: IfK {k: self.tr.k, co: Even::default(), tr: Not::default(), fa: (), i: ()}
+        }
+    }
+}
+
+// `∃if((>= k), if odd(k2) {even} else {odd}, false_1)`
+// `\(x: bool) = if k == max {if odd(k2) {not(x)} else {true_1(x)}} else {true_1(x)}`
+impl<T: Nat> ExPath for If<GeK<T>, IfK<T, Odd<T>, Even<T>, Odd<T>>, False1<T>> {
+    type Lift = IfK<T, EqK<T>, IfK<T, Odd<T>, Not, ()>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.co.k, co: EqK {k: T::MAX, i: ()}, i: (), fa: (),
+            tr: IfK {k: self.tr.k, co: Odd::default(), tr: Not::default(), fa: (), i: ()}
+        }
+    }
+}
+
+// `∃if((< k), odd, even){(= k2)}`
+// `\(x: bool) = if k2 < k {if odd(k2) {id(x)} else {not(x)}} else {if even(k2) {id(x)} else {not{x}}}`
+impl<T: Nat> ExPath for If<LtK<T>, Odd<T>, Even<T>, EqK<T>> {
+    type Lift = IfK<T, LtK<T>,
+                    IfK<T, Odd<T>, Id<bool>, Not>,
+                    IfK<T, Even<T>, Id<bool>, Not>
+                >;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, i: (),
+            co: self.co,
+            tr: IfK {k: self.i.k, co: Odd::default(), tr: Id::default(), fa: Not::default(), i: ()},
+            fa: IfK {k: self.i.k, co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()},
+        }
+    }
+}
+
+// `∃if((< k), even, odd){(= k2)}`
+// `\(x: bool) = if k2 < k {if even(k2) {id(x)} else {not(x)}} else {if odd(k2) {id(x)} else {not(x)}}`
+impl<T: Nat> ExPath for If<LtK<T>, Even<T>, Odd<T>, EqK<T>> {
+    type Lift = IfK<T, LtK<T>,
+                    IfK<T, Even<T>, Id<bool>, Not>,
+                    IfK<T, Odd<T>, Id<bool>, Not>
+                >;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, i: (),
+            co: self.co,
+            tr: IfK {k: self.i.k, co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()},
+            fa: IfK {k: self.i.k, co: Odd::default(), tr: Id::default(), fa: Not::default(), i: ()},
+        }
+    }
+}
+
+// `∃if((>= k), odd, even){(= k2)}`
+// `\(x: bool) = if k2 >= k {if odd(k2) {id(x)} else {not(x)}} else {if even(k2) {id(x)} else {not(x)}}`
+impl<T: Nat> ExPath for If<GeK<T>, Odd<T>, Even<T>, EqK<T>> {
+    type Lift = IfK<T, GeK<T>,
+                    IfK<T, Odd<T>, Id<bool>, Not>,
+                    IfK<T, Even<T>, Id<bool>, Not>
+                >;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, i: (), co: self.co,
+            tr: IfK {k: self.i.k, co: Odd::default(), tr: Id::default(), fa: Not::default(), i: ()},
+            fa: IfK {k: self.i.k, co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()}
+        }
+    }
+}
+
+// `∃if((>= k), even, odd){(= k2)}`
+// `\(x: bool) = if k2 >= k {if even(k2) {id(x)} else {not(x)}} else {if odd(k2) {id(x)} else {not(x)}}`
+impl<T: Nat> ExPath for If<GeK<T>, Even<T>, Odd<T>, EqK<T>> {
+    type Lift = IfK<T, GeK<T>,
+                    IfK<T, Even<T>, Id<bool>, Not>,
+                    IfK<T, Odd<T>, Id<bool>, Not>
+                >;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, i: (), co: self.co,
+            tr: IfK {k: self.i.k, co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()},
+            fa: IfK {k: self.i.k, co: Odd::default(), tr: Id::default(), fa: Not::default(), i: ()}
+        }
+    }
+}
+
+// `∃odd{(= k)}`
+// `\(x: bool) = if odd(k) {id(x)} else {not(x)}`
+impl<T: Nat> ExPath for Odd<T, EqK<T>> {
+    type Lift = IfK<T, Odd<T>, Id<bool>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: Odd::default(), tr: Id::default(), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃(= k){= k2}`
+// `\(x: bool) = if k == k2 {id(x)} else {not(x)}`
+impl<T: Nat> ExPath for EqK<T, EqK<T>> {
+    type Lift = IfK<T, EqK<T>, Id<bool>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: EqK {k: self.k, i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃(< k){= k2}`
+// `\(x: bool) = if k2 < k {id(x)} else {not(x)}`
+impl<T: Nat> ExPath for LtK<T, EqK<T>> {
+    type Lift = IfK<T, LtK<T>, Id<bool>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: LtK {k: self.k, i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃(>= k){= k2}`
+// `\(x: bool) = if k2 >= k {id(x)} else {not(x)}`
+impl<T: Nat> ExPath for GeK<T, EqK<T>> {
+    type Lift = IfK<T, GeK<T>, Id<bool>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: GeK {k: self.k, i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃add(k){= k2}`
+// `\(x: nat) = if k2 <= max - k {(= k + k2)(x)} else {false_1(x)}`
+// The `k + k2` constant saturates in the unused branch.
+impl<T: Nat> ExPath for AddK<T, EqK<T>> {
+    type Lift = IfK<T, LeK<T>, EqK<T>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: LeK {k: T::MAX - self.k, i: ()}, i: (),
+            tr: EqK {k: self.k.saturating_add(self.i.k), i: ()},
+            fa: False1::default(),
+        }
+    }
+}
+
+// `∃mul(k){(= k2)}`
+// `\(x: nat) = if k2 <= max / k {(= k * k2)(x)} else {false_1(x)}`
+// This is also correct for `k == 0`, where the product never overflows.
+// The constant saturates in the unused branch.
+impl<T: Nat> ExPath for MulK<T, EqK<T>> {
+    type Lift = IfK<T, LeK<T>, EqK<T>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: LeK {k: T::MAX.checked_div(self.k).unwrap_or(T::MAX), i: ()}, i: (),
+            tr: EqK {k: self.k.saturating_mul(self.i.k), i: ()},
+            fa: False1::default(),
+        }
+    }
+}
+
+// `∃mul(k){even} => (% 2 * k = 0)`
+// When `2 * k` overflows, only `k * 0` is reachable, which is `(% 0 = 0)`.
+impl<T: Nat> ExPath for MulK<T, Even<T>> {
+    type Lift = ModK<T>;
+    fn ex_path(&self) -> Self::Lift {
+        ModK {m: self.k.checked_mul(T::from(2)).unwrap_or(T::ZERO), r: T::ZERO, i: ()}
+    }
+}
+
+// `∃mul(k){odd} => (% 2 * k = k)`
+// When `2 * k` overflows, only `k * 1` is reachable, which is `(% 0 = k)`.
+impl<T: Nat> ExPath for MulK<T, Odd<T>> {
+    type Lift = ModK<T>;
+    fn ex_path(&self) -> Self::Lift {
+        ModK {m: self.k.checked_mul(T::from(2)).unwrap_or(T::ZERO), r: self.k, i: ()}
+    }
+}
+
+// `∃mul(k){(>= k2)}`
+// `\(x: nat) = if k2 <= max / k {if((>= k * k2), (% k = 0), false_1)(x)} else {false_1(x)}`
+// The constant saturates in the unused branch.
+impl<T: Nat> ExPath for MulK<T, GeK<T>> {
+    type Lift = IfK<T, LeK<T>, If<GeK<T>, ModK<T>, False1<T>>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: LeK {k: T::MAX.checked_div(self.k).unwrap_or(T::MAX), i: ()}, i: (),
+            tr: If {
+                co: GeK {k: self.k.saturating_mul(self.i.k), i: ()},
+                tr: ModK {m: self.k, r: T::ZERO, i: ()}, fa: False1::default(), i: ()
+            },
+            fa: False1::default(),
+        }
+    }
+}
+
+// `∃mul(k){(< k2)}`
+// `\(x: nat) = if k2 == 0 {false_1(x)} else {if((<= k * min(k2 - 1, max / k)), (% k = 0), false_1)(x)}`
+// The largest input is the smaller of `k2 - 1` and the largest input that does not overflow.
+impl<T: Nat> ExPath for MulK<T, LtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<T>, If<LeK<T>, ModK<T>, False1<T>>>;
+    fn ex_path(&self) -> Self::Lift {
+        let max = self.i.k.saturating_sub(T::ONE).min(T::MAX.checked_div(self.k).unwrap_or(T::MAX));
+        IfK {
+            k: self.i.k, co: EqK {k: T::ZERO, i: ()}, i: (),
+            tr: False1::default(),
+            fa: If {
+                co: LeK {k: self.k * max, i: ()},
+                tr: ModK {m: self.k, r: T::ZERO, i: ()}, fa: False1::default(), i: ()
+            },
+        }
+    }
+}
+
+// `∃mul{(= k)} => (= k * k)`, since both arguments are `k`.
+// `\(x: nat) = if k <= max / k {(= k * k)(x)} else {false_1(x)}`
+impl<T: Nat> ExPath for Mul<T, EqK<T>> {
+    type Lift = IfK<T, LeK<T>, EqK<T>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        MulK {k: self.i.k, i: self.i}.ex_path()
+    }
+}
+
+// `∃mul{((= k), (= k2))}`
+// `\(x: nat) = if k2 <= max / k {(= k * k2)(x)} else {false_1(x)}`
+impl<T: Nat> ExPath for Mul<T, (EqK<T>, EqK<T>)> {
+    type Lift = IfK<T, LeK<T>, EqK<T>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        MulK {k: self.i.0.k, i: self.i.1}.ex_path()
+    }
+}
+
+// `∃even{(>= k)}`
+// `\(x: bool) = if k == max {not(x)} else {true_1(x)}`
+// There is no even number greater or equal to the largest unsigned integer.
+// `add(k)` maps `(<= max - k)` onto `(>= k)`, so this is written as
+// `∃add(k)[even]{∃even{(<= max - k)}}` to make `add(k)[even]` type check.
+// `\(x: bool) = if even(k) {∃even{(<= max - k)}(x)} else {∃odd{(<= max - k)}(x)}`
+impl<T: Nat> ExPath for Even<T, GeK<T>> {
+    type Lift = IfK<T, Even<T>, IfK<T, EqK<T>, Id<bool>, ()>, IfK<T, EqK<T>, Not, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        let k = T::MAX - self.i.k;
+        IfK {
+            k: self.i.k, co: Even::default(), i: (),
+            tr: IfK {k, co: EqK {k: T::ZERO, i: ()}, tr: Id::default(), fa: (), i: ()},
+            fa: IfK {k, co: EqK {k: T::ZERO, i: ()}, tr: Not::default(), fa: (), i: ()},
+        }
+    }
+}
+
+// `∃if((< k), odd, even){(>= k2)}`
+// `true` is returned for odd numbers in `[k2, k)` and even numbers `>= k` and `>= k2`.
+// `false` is returned for `max`.
+// `k2 == max => ∃if((< k), odd, even){(= max)}`
+// `             ∃even{(= max)}`
+// `             not`
+// `k == max => ∃odd{(>= k2)} => if k2 == max - 1 {not} else {true_1}`
+// `\(x: bool) = if k2 == max {not(x)} else {if k == max {if k2 == max - 1 {not(x)} else {true_1(x)}} else {true_1(x)}}`
+impl<T: Nat> ExPath for If<LtK<T>, Odd<T>, Even<T>, GeK<T>> {
+    type Lift = IfK<T, EqK<T>, Not, IfK<T, EqK<T>, IfK<T, EqK<T>, Not, ()>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: Not::default(), i: (),
+            fa: IfK {
+                k: self.co.k, co: EqK {k: T::MAX, i: ()}, fa: (), i: (),
+                tr: IfK {k: self.i.k, co: EqK {k: T::MAX - T::ONE, i: ()}, tr: Not::default(), fa: (), i: ()}
             }
         }
+    }
+}
 
-        impl ExPath for EqK<$t, Even<$t>> {
-            type Lift = IfK<$t, Even<$t>, (), Not>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.k, co: Even::default(), tr: (), fa: Not::default(), i: ()}
+// `∃if((< k), even, odd){(>= k2)}`
+// `true` is returned for `max`.
+// `\(x: bool) = if k2 == max {id(x)} else {if k == max {if k2 == max - 1 {id(x)} else {true_1(x)}} else {true_1(x)}}`
+impl<T: Nat> ExPath for If<LtK<T>, Even<T>, Odd<T>, GeK<T>> {
+    type Lift = IfK<T, EqK<T>, Id<bool>, IfK<T, EqK<T>, IfK<T, EqK<T>, Id<bool>, ()>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: Id::default(), i: (),
+            fa: IfK {
+                k: self.co.k, co: EqK {k: T::MAX, i: ()}, fa: (), i: (),
+                tr: IfK {k: self.i.k, co: EqK {k: T::MAX - T::ONE, i: ()}, tr: Id::default(), fa: (), i: ()}
             }
         }
+    }
+}
 
-        impl ExPath for EqK<$t, Odd<$t>> {
-            type Lift = IfK<$t, Odd<$t>, (), Not>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.k, co: Odd::default(), tr: (), fa: Not::default(), i: ()}
+// `∃if((>= k), odd, even){(>= k2)} <=> ∃if((< k), even, odd){(>= k2)}`
+// `\(x: bool) = if k2 == max {id(x)} else {if k == max {if k2 == max - 1 {id(x)} else {true_1(x)}} else {true_1(x)}}`
+impl<T: Nat> ExPath for If<GeK<T>, Odd<T>, Even<T>, GeK<T>> {
+    type Lift = IfK<T, EqK<T>, Id<bool>, IfK<T, EqK<T>, IfK<T, EqK<T>, Id<bool>, ()>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: Id::default(), i: (),
+            fa: IfK {
+                k: self.co.k, co: EqK {k: T::MAX, i: ()}, fa: (), i: (),
+                tr: IfK {k: self.i.k, co: EqK {k: T::MAX - T::ONE, i: ()}, tr: Id::default(), fa: (), i: ()}
             }
         }
+    }
+}
 
-        // `∃add(k){even}`
-        // `\(x: nat) = if x >= k {if even(k) {even(x)} else {odd(x)}} else {false}`
-        impl ExPath for AddK<$t, Even<$t>> {
-            type Lift = If<GeK<$t>, IfK<$t, Even<$t>, Even<$t>, Odd<$t>>, False1<$t>>;
-            fn ex_path(&self) -> Self::Lift {
-                If {
-                    co: GeK {k: self.k, i: ()},
-                    tr: IfK {k: self.k, co: Even::default(), tr: Even::default(), fa: Odd::default(), i: ()},
-                    fa: False1::default(), i: ()
-                }
+// `∃if((>= k), even, odd){(>= k2)} <=> ∃if((< k), odd, even){(>= k2)}`
+// `\(x: bool) = if k2 == max {not(x)} else {if k == max {if k2 == max - 1 {not(x)} else {true_1(x)}} else {true_1(x)}}`
+impl<T: Nat> ExPath for If<GeK<T>, Even<T>, Odd<T>, GeK<T>> {
+    type Lift = IfK<T, EqK<T>, Not, IfK<T, EqK<T>, IfK<T, EqK<T>, Not, ()>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: Not::default(), i: (),
+            fa: IfK {
+                k: self.co.k, co: EqK {k: T::MAX, i: ()}, fa: (), i: (),
+                tr: IfK {k: self.i.k, co: EqK {k: T::MAX - T::ONE, i: ()}, tr: Not::default(), fa: (), i: ()}
             }
         }
+    }
+}
 
-        // `∃add(k){odd}`
-        // `\(x: nat) = if x >= k {if odd(k) {even(x)} else {odd(x)}} else {false}`
-        impl ExPath for AddK<$t, Odd<$t>> {
-            type Lift = If<GeK<$t>, IfK<$t, Odd<$t>, Even<$t>, Odd<$t>>, False1<$t>>;
-            fn ex_path(&self) -> Self::Lift {
-                If {
-                    co: GeK {k: self.k, i: ()},
-                    tr: IfK {k: self.k, co: Odd::default(), tr: Even::default(), fa: Odd::default(), i: ()},
-                    fa: False1::default(), i: ()
+// `∃odd{(>= k)}`
+// `\(x: bool) = if k == max {id(x)} else {true_1(x)}`
+// Written as `∃add(k)[odd]{∃odd{(<= max - k)}}`, like `∃even{(>= k)}`.
+// `\(x: bool) = if even(k) {∃odd{(<= max - k)}(x)} else {∃even{(<= max - k)}(x)}`
+impl<T: Nat> ExPath for Odd<T, GeK<T>> {
+    type Lift = IfK<T, Even<T>, IfK<T, EqK<T>, Not, ()>, IfK<T, EqK<T>, Id<bool>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        let k = T::MAX - self.i.k;
+        IfK {
+            k: self.i.k, co: Even::default(), i: (),
+            tr: IfK {k, co: EqK {k: T::ZERO, i: ()}, tr: Not::default(), fa: (), i: ()},
+            fa: IfK {k, co: EqK {k: T::ZERO, i: ()}, tr: Id::default(), fa: (), i: ()},
+        }
+    }
+}
+
+// `∃(= k){>= k2}`
+// `\(x: bool) = if k < k2 {not(x)} else {if k2 == max {if k == max {id(x)} else {true_1}} else {true_1}}`
+impl<T: Nat> ExPath for EqK<T, GeK<T>> {
+    type Lift = IfK<T, LtK<T>, Not, IfK<T, EqK<T>, IfK<T, EqK<T>, Id<bool>, ()>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, i: (),
+            co: LtK {k: self.i.k, i: ()},
+            tr: Not::default(),
+            fa: IfK {k: self.i.k, co: EqK {k: T::MAX, i: ()},
+                tr: IfK {k: self.k, co: EqK {k: T::MAX, i: ()}, tr: Id::default(), fa: (), i: ()},
+                fa: (),
+                i: ()
+            },
+        }
+    }
+}
+
+// `∃(< k){(>= k2)}`
+// `\(x: bool) = if k2 >= k {not(x)} else {if k == 0 {not(x)} else {true_1}}`
+impl<T: Nat> ExPath for LtK<T, GeK<T>> {
+    type Lift = IfK<T, GeK<T>, Not, IfK<T, EqK<T>, Not, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: GeK {k: self.k, i: ()}, tr: Not::default(),
+             fa: IfK {k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Not::default(), fa: (), i: ()}, i: ()}
+    }
+}
+
+// `∃(>= k){(>= k2)}`
+// `k2 == max => ∃(>= k){(>= max)}`
+// `             ∃(>= k){(= max)} => k <= max`
+// `             id`
+// `k == 0 => ∃(>= 0){(>= k2)}`
+// `          ∃true_1{(>= k2)}`
+// `          ∃true_1`
+// `          id`
+// `k > k2 => true_1 else id
+// `\(x: bool) = if k2 < k {true_1(x)} else {id(x)}`
+impl<T: Nat> ExPath for GeK<T, GeK<T>> {
+    type Lift = IfK<T, LtK<T>, (), Id<bool>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: LtK {k: self.k, i: ()}, tr: (), fa: Id::default(), i: ()}
+    }
+}
+
+// `∃add(k){(>= k2)}`
+// `\(x: nat) = if k2 <= max - k {(>= k + k2)(x)} else {false_1(x)}`
+// The `k + k2` constant saturates in the unused branch.
+impl<T: Nat> ExPath for AddK<T, GeK<T>> {
+    type Lift = IfK<T, LeK<T>, GeK<T>, False1<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: LeK {k: T::MAX - self.k, i: ()}, i: (),
+            tr: GeK {k: self.k.saturating_add(self.i.k), i: ()},
+            fa: False1::default(),
+        }
+    }
+}
+
+// `∃even{(< k)}`
+// `k == 0 => ∃even{(< 0)} => false_1`
+// `k == 1 => ∃even{(< 1)} => id`
+// `k == 2 => ∃even{(< 2)} => true_1`
+// `\(x: bool) = if k < 2 {if k == 0 {not(x)} else {id(x)}} else {true_1(x)}`
+impl<T: Nat> ExPath for Even<T, LtK<T>> {
+    type Lift = IfK<T, LtK<T>, IfK<T, EqK<T>, False1<bool>, Id<bool>>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: LtK {k: T::from(2), i: ()},
+            tr: IfK {k: self.i.k, co: EqK {k: T::ZERO, i: ()}, tr: False1::default(), fa: Id::default(), i: ()},
+            fa: (), i: ()
+        }
+    }
+}
+
+// `∃if((< k), odd, even){(< k2)}`
+// `k >= k2 => ∃odd{(< k2)}`
+// `k == 0 => ∃even{(< k2)}`
+// `k == 1 => ∃if((< 1), odd, even){(< k2)}`
+// `          ∃if((= 0), false, even){(< k2)} => if k2 < 3 {not} else {true_1}`
+// `k >= 2 => ∃if((< k), odd, even){(< 2)} => true_1`
+// `\(x: bool) = if k >= k2 {(∃odd{(< k2)})(x)} else {if k == 0 {(∃even{(< k2)})(x)} else {if k == 1 && k2 < 3 {not(x)} else {true_1(x)}}}}`
+impl<T: Nat> ExPath for If<LtK<T>, Odd<T>, Even<T>, LtK<T>> {
+    type Lift = IfK<T, GeK<T>, <Odd<T, LtK<T>> as ExPath>::Lift,
+                    IfK<T, EqK<T>, <Even<T, LtK<T>> as ExPath>::Lift,
+                        IfK<T, EqK<T>, IfK<T, LtK<T>, Not, ()>, ()>>
+                >;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.co.k, co: GeK {k: self.i.k, i: ()}, i: (),
+            tr: Odd::<T>::default().i(self.i).ex_path(),
+            fa: IfK {
+                k: self.co.k, co: EqK {k: T::ZERO, i: ()}, i: (),
+                tr: Even::<T>::default().i(self.i).ex_path(),
+                fa: IfK {
+                    k: self.co.k, co: EqK {k: T::ONE, i: ()}, fa: (), i: (),
+                    tr: IfK {k: self.i.k, co: LtK {k: T::from(3), i: ()}, tr: Not::default(), fa: (), i: ()}
                 }
             }
         }
+    }
+}
 
-        // `∃if((>= k), if even(k) {even} else {odd}, false_1)`
-        // `\(x: bool) = if even(k) {true_1(x)} else {not(x)}`
-        impl ExPath for If<GeK<$t>, IfK<$t, Even<$t>, Even<$t>, Odd<$t>>, False1<$t>> {
-            type Lift = IfK<$t, Even<$t>, (), Not>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.co.k, co: Even::default(), tr: (), fa: Not::default(), i: ()}
+// `∃if((< k), even, odd){(< k2)}`
+// `k >= k2 => ∃even{(< k2)}`
+// `k == 0 => ∃odd{(< k2)}`
+// `k == 1 => ∃if((= 0), true, odd){(< k2)} => if k2 < 3 {id} else {true_1}`
+// `k >= 2 => true_1`
+impl<T: Nat> ExPath for If<LtK<T>, Even<T>, Odd<T>, LtK<T>> {
+    type Lift = IfK<T, GeK<T>, <Even<T, LtK<T>> as ExPath>::Lift,
+                    IfK<T, EqK<T>, <Odd<T, LtK<T>> as ExPath>::Lift,
+                        IfK<T, EqK<T>, IfK<T, LtK<T>, Id<bool>, ()>, ()>>
+                >;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.co.k, co: GeK {k: self.i.k, i: ()}, i: (),
+            tr: Even::<T>::default().i(self.i).ex_path(),
+            fa: IfK {
+                k: self.co.k, co: EqK {k: T::ZERO, i: ()}, i: (),
+                tr: Odd::<T>::default().i(self.i).ex_path(),
+                fa: IfK {
+                    k: self.co.k, co: EqK {k: T::ONE, i: ()}, fa: (), i: (),
+                    tr: IfK {k: self.i.k, co: LtK {k: T::from(3), i: ()}, tr: Id::default(), fa: (), i: ()}
+                }
             }
         }
+    }
+}
 
-        // `∃if((< k), odd, even){(= k2)}`
-        // `\(x: bool) = if k2 < k {if odd(k2) {id(x)} else {not(x)}} else {if even(k2) {id(x)} else {not{x}}}`
-        impl ExPath for If<LtK<$t>, Odd<$t>, Even<$t>, EqK<$t>> {
-            type Lift = IfK<$t, LtK<$t>,
-                            IfK<$t, Odd<$t>, Id<bool>, Not>,
-                            IfK<$t, Even<$t>, Id<bool>, Not>
-                        >;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {
-                    k: self.i.k, i: (),
-                    co: self.co.clone(),
-                    tr: IfK {k: self.i.k, co: Odd::default(), tr: Id::default(), fa: Not::default(), i: ()},
-                    fa: IfK {k: self.i.k, co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()},
+// `∃if((>= k), odd, even){(< k2)} <=> ∃if((< k), even, odd){(< k2)}`
+impl<T: Nat> ExPath for If<GeK<T>, Odd<T>, Even<T>, LtK<T>> {
+    type Lift = IfK<T, GeK<T>, <Even<T, LtK<T>> as ExPath>::Lift,
+                    IfK<T, EqK<T>, <Odd<T, LtK<T>> as ExPath>::Lift,
+                        IfK<T, EqK<T>, IfK<T, LtK<T>, Id<bool>, ()>, ()>>
+                >;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.co.k, co: GeK {k: self.i.k, i: ()}, i: (),
+            tr: Even::<T>::default().i(self.i).ex_path(),
+            fa: IfK {
+                k: self.co.k, co: EqK {k: T::ZERO, i: ()}, i: (),
+                tr: Odd::<T>::default().i(self.i).ex_path(),
+                fa: IfK {
+                    k: self.co.k, co: EqK {k: T::ONE, i: ()}, fa: (), i: (),
+                    tr: IfK {k: self.i.k, co: LtK {k: T::from(3), i: ()}, tr: Id::default(), fa: (), i: ()}
                 }
             }
         }
+    }
+}
 
-        // `∃if((< k), even, odd){(= k2)}`
-        // `\(x: bool) = if k2 < k {if even(k2) {id(x)} else {not(x)}} else {if odd(k2) {id(x)} else {not(x)}}`
-        impl ExPath for If<LtK<$t>, Even<$t>, Odd<$t>, EqK<$t>> {
-            type Lift = IfK<$t, LtK<$t>,
-                            IfK<$t, Even<$t>, Id<bool>, Not>,
-                            IfK<$t, Odd<$t>, Id<bool>, Not>
-                        >;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {
-                    k: self.i.k, i: (),
-                    co: self.co.clone(),
-                    tr: IfK {k: self.i.k, co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()},
-                    fa: IfK {k: self.i.k, co: Odd::default(), tr: Id::default(), fa: Not::default(), i: ()},
+// `∃if((>= k), even, odd){(< k2)} <=> ∃if((< k), odd, even){(< k2)}`
+impl<T: Nat> ExPath for If<GeK<T>, Even<T>, Odd<T>, LtK<T>> {
+    type Lift = IfK<T, GeK<T>, <Odd<T, LtK<T>> as ExPath>::Lift,
+                    IfK<T, EqK<T>, <Even<T, LtK<T>> as ExPath>::Lift,
+                        IfK<T, EqK<T>, IfK<T, LtK<T>, Not, ()>, ()>>
+                >;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.co.k, co: GeK {k: self.i.k, i: ()}, i: (),
+            tr: Odd::<T>::default().i(self.i).ex_path(),
+            fa: IfK {
+                k: self.co.k, co: EqK {k: T::ZERO, i: ()}, i: (),
+                tr: Even::<T>::default().i(self.i).ex_path(),
+                fa: IfK {
+                    k: self.co.k, co: EqK {k: T::ONE, i: ()}, fa: (), i: (),
+                    tr: IfK {k: self.i.k, co: LtK {k: T::from(3), i: ()}, tr: Not::default(), fa: (), i: ()}
                 }
             }
         }
+    }
+}
 
-        // `∃if((>= k), odd, even){(= k2)}`
-        // `\(x: bool) = if k2 >= k {if odd(k2) {id(x)} else {not(x)}} else {if even(k2) {id(x)} else {not(x)}}`
-        impl ExPath for If<GeK<$t>, Odd<$t>, Even<$t>, EqK<$t>> {
-            type Lift = IfK<$t, GeK<$t>,
-                            IfK<$t, Odd<$t>, Id<bool>, Not>,
-                            IfK<$t, Even<$t>, Id<bool>, Not>
-                        >;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {
-                    k: self.i.k, i: (), co: self.co.clone(),
-                    tr: IfK {k: self.i.k, co: Odd::default(), tr: Id::default(), fa: Not::default(), i: ()},
-                    fa: IfK {k: self.i.k, co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()}
-                }
+// `∃odd{(< k)}`
+// `k == 0 => ∃odd{(< 0)} => false_1`
+// `k == 1 => ∃odd{(< 1)} => ∃odd{(= 0)} => not`
+// `k == 2 => ∃odd{(< 2)} => true_1`
+// `\(x: bool) = if k < 2 {if k == 0 {false_1(x)} else {not(x)}} else {true_1}`
+impl<T: Nat> ExPath for Odd<T, LtK<T>> {
+    type Lift = IfK<T, LtK<T>, IfK<T, EqK<T>, False1<bool>, Not>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: LtK {k: T::from(2), i: ()}, i: (),
+            tr: IfK {k: self.i.k, co: EqK {k: T::ZERO, i: ()}, tr: False1::default(),
+                     fa: Not::default(), i: ()},
+            fa: ()
+        }
+    }
+}
+
+// `∃(= k){(< k2)}`
+// `k2 == 0 => false_1`
+// `k >= k2 => not`
+// `k2 == 1 => ∃(= 0){(= 0)} => id`
+//          else true_1`
+// `\(x: bool) = if k2 == 0 {false_1(x)} else {if k < k2 {if k2 == 1 {id(x)} else {true_1(x)}} else {not(x)}}`
+impl<T: Nat> ExPath for EqK<T, LtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>, IfK<T, LtK<T>, IfK<T, EqK<T>, Id<bool>, ()>, Not>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::ZERO, i: ()}, i: (),
+            tr: False1::default(),
+            fa: IfK {
+                k: self.k, co: LtK {k: self.i.k, i: ()}, fa: Not::default(), i: (),
+                tr: IfK {k: self.i.k, co: EqK {k: T::ONE, i: ()}, tr: Id::default(), fa: (), i: ()}
             }
         }
+    }
+}
 
-        // `∃if((>= k), even, odd){(= k2)}`
-        // `\(x: bool) = if k2 >= k {if even(k2) {id(x)} else {not(x)}} else {if odd(k2) {id(x)} else {not(x)}}`
-        impl ExPath for If<GeK<$t>, Even<$t>, Odd<$t>, EqK<$t>> {
-            type Lift = IfK<$t, GeK<$t>,
-                            IfK<$t, Even<$t>, Id<bool>, Not>,
-                            IfK<$t, Odd<$t>, Id<bool>, Not>
-                        >;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {
-                    k: self.i.k, i: (), co: self.co.clone(),
-                    tr: IfK {k: self.i.k, co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()},
-                    fa: IfK {k: self.i.k, co: Odd::default(), tr: Id::default(), fa: Not::default(), i: ()}
-                }
+// `∃(< k){(< k2)}`
+// `k2 == 0 => `∃(< k){(< 0)} => false_1`
+// `k == 0` => not
+// `k >= k2 => id else true_1`
+// `\(x: bool) = if k2 == 0 {false_1(x)} else {if k == 0 {not(x)} else {if k >= k2 {id(x)} else {true_1(x)}}}`
+impl<T: Nat> ExPath for LtK<T, LtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>,
+                    IfK<T, EqK<T>, Not, IfK<T, GeK<T>, Id<bool>, ()>>
+                >;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::ZERO, i: ()}, i: (),
+            tr: False1::default(),
+            fa: IfK {
+                k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Not::default(),
+                fa: IfK {k: self.k, co: GeK {k: self.i.k, i: ()},
+                         tr: Id::default(), fa: (), i: ()}, i: ()
             }
         }
+    }
+}
 
-        // `∃odd{(= k)}`
-        // `\(x: bool) = if odd(k) {id(x)} else {not(x)}`
-        impl ExPath for Odd<$t, EqK<$t>> {
-            type Lift = IfK<$t, Odd<$t>, Id<bool>, Not>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: Odd::default(), tr: Id::default(), fa: Not::default(), i: ()}
+// `∃(>= k){(< k2)}`
+// `k2 == 0 => false_1`
+// `k == 0 => id`
+// `k >= k2 => not else true_1`
+// `\(x: bool) = if k2 == 0 {false_1(x)} else {if k == 0 {id(x)} else {if k < k2 {true_1(x)} else {not(x)}}}`
+impl<T: Nat> ExPath for GeK<T, LtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>, IfK<T, EqK<T>, Id<bool>, IfK<T, LtK<T>, (), Not>>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::ZERO, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {
+                k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Id::default(), i: (),
+                fa: IfK {k: self.k, co: LtK {k: self.i.k, i: ()}, tr: (), fa: Not::default(), i: ()}
             }
         }
+    }
+}
 
-        // `∃(= k){= k2}`
-        // `\(x: bool) = if k == k2 {id(x)} else {not(x)}`
-        impl ExPath for EqK<$t, EqK<$t>> {
-            type Lift = IfK<$t, EqK<$t>, Id<bool>, Not>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: EqK {k: self.k, i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+// `∃add(k){(< k2)}`
+// `[k, k + k2)`, which covers `[k, max]` when `k + k2 > max`.
+// `\(x: nat) = if k2 <= max - k {[k, k + k2)(x)} else {(>= k)(x)}`
+// The `k + k2` constant saturates in the unused branch.
+impl<T: Nat> ExPath for AddK<T, LtK<T>> {
+    type Lift = IfK<T, LeK<T>, RangeK<T>, GeK<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: LeK {k: T::MAX - self.k, i: ()}, i: (),
+            tr: RangeK {lo: self.k, hi: self.k.saturating_add(self.i.k), i: ()},
+            fa: GeK {k: self.k, i: ()},
+        }
+    }
+}
+
+// `∃[lo, hi)`
+// `\(x: bool) = if lo < hi {true_1(x)} else {not(x)}`
+// Since `hi <= max`, the range never covers `max`.
+impl<T: Nat> ExPath for RangeK<T> {
+    type Lift = IfK<T, LtK<T>, (), Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.lo, co: LtK {k: self.hi, i: ()}, tr: (), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃add(k){[lo, hi)}`
+// `[lo + k, hi + k)`, which covers `[lo + k, max]` when `hi + k > max`.
+// `\(x: nat) = if hi <= max - k {[lo + k, hi + k)(x)} else {(> lo + k - 1)(x)}`
+// An empty range or `lo + k > max` gives `(> max)`, which is `false_1`.
+// The `hi + k` constant saturates in the unused branch.
+impl<T: Nat> ExPath for AddK<T, RangeK<T>> {
+    type Lift = IfK<T, LeK<T>, RangeK<T>, GtK<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        let gt = if self.i.lo < self.i.hi {
+            self.i.lo.checked_add(self.k).map(|lo| lo.saturating_sub(T::ONE)).unwrap_or(T::MAX)
+        } else {T::MAX};
+        IfK {
+            k: self.i.hi, co: LeK {k: T::MAX - self.k, i: ()}, i: (),
+            tr: RangeK {
+                lo: self.i.lo.saturating_add(self.k),
+                hi: self.i.hi.saturating_add(self.k),
+                i: ()
+            },
+            fa: GtK {k: gt, i: ()},
+        }
+    }
+}
+
+// `∃even{[lo, hi)}`
+// `\(x: bool) = if lo >= hi {false_1(x)} else {if lo == hi - 1 {if even(lo) {id(x)} else {not(x)}} else {true_1(x)}}`
+// The `hi - 1` constant is not used when `hi == 0`.
+impl<T: Nat> ExPath for Even<T, RangeK<T>> {
+    type Lift = IfK<T, GeK<T>, False1<bool>, IfK<T, EqK<T>, IfK<T, Even<T>, Id<bool>, Not>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.lo, co: GeK {k: self.i.hi, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {
+                k: self.i.lo, co: EqK {k: self.i.hi.saturating_sub(T::ONE), i: ()}, fa: (), i: (),
+                tr: IfK {k: self.i.lo, co: Even::default(), tr: Id::default(), fa: Not::default(), i: ()}
             }
         }
+    }
+}
 
-        // `∃(< k){= k2}`
-        // `\(x: bool) = if k2 < k {id(x)} else {not(x)}`
-        impl ExPath for LtK<$t, EqK<$t>> {
-            type Lift = IfK<$t, LtK<$t>, Id<bool>, Not>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: LtK {k: self.k, i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+// `∃odd{[lo, hi)}`
+// `\(x: bool) = if lo >= hi {false_1(x)} else {if lo == hi - 1 {if even(lo) {not(x)} else {id(x)}} else {true_1(x)}}`
+// The `hi - 1` constant is not used when `hi == 0`.
+impl<T: Nat> ExPath for Odd<T, RangeK<T>> {
+    type Lift = IfK<T, GeK<T>, False1<bool>, IfK<T, EqK<T>, IfK<T, Even<T>, Not, Id<bool>>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.lo, co: GeK {k: self.i.hi, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {
+                k: self.i.lo, co: EqK {k: self.i.hi.saturating_sub(T::ONE), i: ()}, fa: (), i: (),
+                tr: IfK {k: self.i.lo, co: Even::default(), tr: Not::default(), fa: Id::default(), i: ()}
             }
         }
+    }
+}
 
-        // `∃(>= k){= k2}`
-        // `\(x: bool) = if k2 >= k {id(x)} else {not(x)}`
-        impl ExPath for GeK<$t, EqK<$t>> {
-            type Lift = IfK<$t, GeK<$t>, Id<bool>, Not>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: GeK {k: self.k, i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+// `∃(= k){[lo, hi)}`
+// `\(x: bool) = if lo >= hi {false_1(x)} else {if [lo, hi)(k) {if lo == hi - 1 {id(x)} else {true_1(x)}} else {not(x)}}`
+// The `hi - 1` constant is not used when `hi == 0`.
+impl<T: Nat> ExPath for EqK<T, RangeK<T>> {
+    type Lift = IfK<T, GeK<T>, False1<bool>, IfK<T, RangeK<T>, IfK<T, EqK<T>, Id<bool>, ()>, Not>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.lo, co: GeK {k: self.i.hi, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {
+                k: self.k, co: RangeK {lo: self.i.lo, hi: self.i.hi, i: ()}, fa: Not::default(), i: (),
+                tr: IfK {k: self.i.lo, co: EqK {k: self.i.hi.saturating_sub(T::ONE), i: ()},
+                         tr: Id::default(), fa: (), i: ()}
             }
         }
+    }
+}
 
-        // `∃add(k){= k2} => (= k + k2)`
-        impl ExPath for AddK<$t, EqK<$t>> {
-            type Lift = EqK<$t>;
-            fn ex_path(&self) -> Self::Lift {
-                EqK {k: self.k + self.i.k, i: ()}
+// `∃(>= k){[lo, hi)}`
+// `\(x: bool) = if lo >= hi {false_1(x)} else {if k < hi {if k > lo {true_1(x)} else {id(x)}} else {not(x)}}`
+impl<T: Nat> ExPath for GeK<T, RangeK<T>> {
+    type Lift = IfK<T, GeK<T>, False1<bool>, IfK<T, LtK<T>, IfK<T, GtK<T>, (), Id<bool>>, Not>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.lo, co: GeK {k: self.i.hi, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {
+                k: self.k, co: LtK {k: self.i.hi, i: ()}, fa: Not::default(), i: (),
+                tr: IfK {k: self.k, co: GtK {k: self.i.lo, i: ()}, tr: (), fa: Id::default(), i: ()}
             }
         }
+    }
+}
 
-        // `∃even{(>= k)}`
-        // `\(x: bool) = if k == max {not(x)} else {true_1(x)}`
-        // There is no even number greater or equal to the largest unsigned integer.
-        impl ExPath for Even<$t, GeK<$t>> {
-            type Lift = IfK<$t, EqK<$t>, Not, ()>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: EqK {k: $($max)*, i: ()}, tr: Not::default(), fa: (), i: ()}
+// `∃(< k){[lo, hi)} <=> not . ∃(>= k){[lo, hi)}`
+// `\(x: bool) = if lo >= hi {false_1(x)} else {if k < hi {if k > lo {true_1(x)} else {not(x)}} else {id(x)}}`
+impl<T: Nat> ExPath for LtK<T, RangeK<T>> {
+    type Lift = IfK<T, GeK<T>, False1<bool>, IfK<T, LtK<T>, IfK<T, GtK<T>, (), Not>, Id<bool>>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.lo, co: GeK {k: self.i.hi, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {
+                k: self.k, co: LtK {k: self.i.hi, i: ()}, fa: Id::default(), i: (),
+                tr: IfK {k: self.k, co: GtK {k: self.i.lo, i: ()}, tr: (), fa: Not::default(), i: ()}
             }
         }
+    }
+}
+
+// `∃(> k)`
+// `\(x: bool) = if k == max {not(x)} else {true_1(x)}`
+impl<T: Nat> ExPath for GtK<T> {
+    type Lift = IfK<T, EqK<T>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: EqK {k: T::MAX, i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(> k){even}`
+// The largest even number is `max - 1`.
+// `\(x: bool) = if k >= max - 1 {not(x)} else {true_1(x)}`
+impl<T: Nat> ExPath for GtK<T, Even<T>> {
+    type Lift = IfK<T, GeK<T>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: GeK {k: T::MAX - T::ONE, i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(> k){odd}`
+// `\(x: bool) = if k == 0 {id(x)} else {if k == max {not(x)} else {true_1(x)}}`
+impl<T: Nat> ExPath for GtK<T, Odd<T>> {
+    type Lift = IfK<T, EqK<T>, Id<bool>, IfK<T, EqK<T>, Not, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Id::default(), i: (),
+            fa: IfK {k: self.k, co: EqK {k: T::MAX, i: ()}, tr: Not::default(), fa: (), i: ()}
+        }
+    }
+}
 
-        // `∃if((< k), odd, even){(>= k2)}`
-        // `\(x: bool) = if ... {...} else {...}`
-        // `k2 >= k => not`
-        // `∃odd{(>= k2)} | ∃even{(>= k2)}`
-        // `∃even{(>= k2)} => if k2 == max {not} else {true_1}`
-        // `∃odd{(>= k2)} => if k2 == max {id} else {true_1}`
-        // `k == max => k2 < max`
-        // `\(x: bool) = if k2 >= k {not(x)} else {true_1}`
-        impl ExPath for If<LtK<$t>, Odd<$t>, Even<$t>, GeK<$t>> {
-            type Lift = IfK<$t, GeK<$t>, Not, ()>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: GeK {k: self.co.k, i: ()}, tr: Not::default(), fa: (), i: ()}
+// `∃(> k){(= k2)}`
+// `\(x: bool) = if k2 > k {id(x)} else {not(x)}`
+impl<T: Nat> ExPath for GtK<T, EqK<T>> {
+    type Lift = IfK<T, GtK<T>, Id<bool>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: GtK {k: self.k, i: ()}, tr: Id::default(), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃(> k){(>= k2)}`
+// `\(x: bool) = if k2 > k {id(x)} else {if k == max {not(x)} else {true_1(x)}}`
+impl<T: Nat> ExPath for GtK<T, GeK<T>> {
+    type Lift = IfK<T, GtK<T>, Id<bool>, IfK<T, EqK<T>, Not, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: GtK {k: self.k, i: ()}, tr: Id::default(), i: (),
+            fa: IfK {k: self.k, co: EqK {k: T::MAX, i: ()}, tr: Not::default(), fa: (), i: ()}
+        }
+    }
+}
+
+// `∃(> k){(< k2)}`
+// `\(x: bool) = if k2 == 0 {false_1(x)} else {if k < k2 - 1 {true_1(x)} else {not(x)}}`
+// The `k2 - 1` constant is not used for `k2 == 0`.
+impl<T: Nat> ExPath for GtK<T, LtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>, IfK<T, LtK<T>, (), Not>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::ZERO, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {k: self.k, co: LtK {k: self.i.k.saturating_sub(T::ONE), i: ()}, tr: (), fa: Not::default(), i: ()}
+        }
+    }
+}
+
+// `∃(> k){(> k2)}`
+// `\(x: bool) = if k2 == max {false_1(x)} else {if k == max {not(x)} else {if k2 < k {true_1(x)} else {id(x)}}}`
+impl<T: Nat> ExPath for GtK<T, GtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>, IfK<T, EqK<T>, Not, IfK<T, LtK<T>, (), Id<bool>>>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {
+                k: self.k, co: EqK {k: T::MAX, i: ()}, tr: Not::default(), i: (),
+                fa: IfK {k: self.i.k, co: LtK {k: self.k, i: ()}, tr: (), fa: Id::default(), i: ()}
             }
         }
+    }
+}
 
-        // `∃if((< k), even, odd){(>= k)}`
-        // `\(x: bool) = if k2 >= k {not(x)} else {true_1}`
-        impl ExPath for If<LtK<$t>, Even<$t>, Odd<$t>, GeK<$t>> {
-            type Lift = IfK<$t, GeK<$t>, Not, ()>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: GeK {k: self.co.k, i: ()}, tr: Not::default(), fa: (), i: ()}
+// `∃(> k){(<= k2)}`
+// `\(x: bool) = if k < k2 {true_1(x)} else {not(x)}`
+impl<T: Nat> ExPath for GtK<T, LeK<T>> {
+    type Lift = IfK<T, LtK<T>, (), Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: LtK {k: self.i.k, i: ()}, tr: (), fa: Not::default(), i: ()}
+    }
+}
+
+// `∃(<= k){c} <=> not . ∃(> k){c}`
+// `∃(<= k)`
+// `\(x: bool) = if k == max {id(x)} else {true_1(x)}`
+impl<T: Nat> ExPath for LeK<T> {
+    type Lift = IfK<T, EqK<T>, Id<bool>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: EqK {k: T::MAX, i: ()}, tr: Id::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(<= k){even}`
+// The largest even number is `max - 1`.
+// `\(x: bool) = if k >= max - 1 {id(x)} else {true_1(x)}`
+impl<T: Nat> ExPath for LeK<T, Even<T>> {
+    type Lift = IfK<T, GeK<T>, Id<bool>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: GeK {k: T::MAX - T::ONE, i: ()}, tr: Id::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(<= k){odd}`
+// `\(x: bool) = if k == 0 {not(x)} else {if k == max {id(x)} else {true_1(x)}}`
+impl<T: Nat> ExPath for LeK<T, Odd<T>> {
+    type Lift = IfK<T, EqK<T>, Not, IfK<T, EqK<T>, Id<bool>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Not::default(), i: (),
+            fa: IfK {k: self.k, co: EqK {k: T::MAX, i: ()}, tr: Id::default(), fa: (), i: ()}
+        }
+    }
+}
+
+// `∃(<= k){(= k2)}`
+// `\(x: bool) = if k2 > k {not(x)} else {id(x)}`
+impl<T: Nat> ExPath for LeK<T, EqK<T>> {
+    type Lift = IfK<T, GtK<T>, Not, Id<bool>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: GtK {k: self.k, i: ()}, tr: Not::default(), fa: Id::default(), i: ()}
+    }
+}
+
+// `∃(<= k){(>= k2)}`
+// `\(x: bool) = if k2 > k {not(x)} else {if k == max {id(x)} else {true_1(x)}}`
+impl<T: Nat> ExPath for LeK<T, GeK<T>> {
+    type Lift = IfK<T, GtK<T>, Not, IfK<T, EqK<T>, Id<bool>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: GtK {k: self.k, i: ()}, tr: Not::default(), i: (),
+            fa: IfK {k: self.k, co: EqK {k: T::MAX, i: ()}, tr: Id::default(), fa: (), i: ()}
+        }
+    }
+}
+
+// `∃(<= k){(< k2)}`
+// `\(x: bool) = if k2 == 0 {false_1(x)} else {if k < k2 - 1 {true_1(x)} else {id(x)}}`
+// The `k2 - 1` constant is not used for `k2 == 0`.
+impl<T: Nat> ExPath for LeK<T, LtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>, IfK<T, LtK<T>, (), Id<bool>>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::ZERO, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {k: self.k, co: LtK {k: self.i.k.saturating_sub(T::ONE), i: ()}, tr: (), fa: Id::default(), i: ()}
+        }
+    }
+}
+
+// `∃(<= k){(> k2)}`
+// `\(x: bool) = if k2 == max {false_1(x)} else {if k == max {id(x)} else {if k2 < k {true_1(x)} else {not(x)}}}`
+impl<T: Nat> ExPath for LeK<T, GtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>, IfK<T, EqK<T>, Id<bool>, IfK<T, LtK<T>, (), Not>>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {
+                k: self.k, co: EqK {k: T::MAX, i: ()}, tr: Id::default(), i: (),
+                fa: IfK {k: self.i.k, co: LtK {k: self.k, i: ()}, tr: (), fa: Not::default(), i: ()}
             }
         }
+    }
+}
 
-        // `∃if((>= k), odd, even){(>= k2)}`
-        // `k2 == max => ∃if((>= k), odd, even){(>= max)}`
-        // `             ∃if((>= k), odd, even){(= max)} => k <= max`
-        // `             ∃odd{(= max)}`
-        // `             id`
-        // `\(x: bool) = if k2 == max {id(x)} else {true_1(x)}}`
-        impl ExPath for If<GeK<$t>, Odd<$t>, Even<$t>, GeK<$t>> {
-            type Lift = IfK<$t, EqK<$t>, Id<bool>, ()>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: EqK {k: $($max)*, i: ()}, tr: Id::default(), fa: (), i: ()}
+// `∃(<= k){(<= k2)}`
+// `\(x: bool) = if k < k2 {true_1(x)} else {id(x)}`
+impl<T: Nat> ExPath for LeK<T, LeK<T>> {
+    type Lift = IfK<T, LtK<T>, (), Id<bool>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: LtK {k: self.i.k, i: ()}, tr: (), fa: Id::default(), i: ()}
+    }
+}
+
+// `∃even{(> k)}`
+// `\(x: bool) = if k == max {false_1(x)} else {if k == max - 1 {not(x)} else {true_1(x)}}`
+impl<T: Nat> ExPath for Even<T, GtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>, IfK<T, EqK<T>, Not, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {k: self.i.k, co: EqK {k: T::MAX - T::ONE, i: ()}, tr: Not::default(), fa: (), i: ()}
+        }
+    }
+}
+
+// `∃odd{(> k)}`
+// `\(x: bool) = if k == max {false_1(x)} else {if k == max - 1 {id(x)} else {true_1(x)}}`
+impl<T: Nat> ExPath for Odd<T, GtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>, IfK<T, EqK<T>, Id<bool>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {k: self.i.k, co: EqK {k: T::MAX - T::ONE, i: ()}, tr: Id::default(), fa: (), i: ()}
+        }
+    }
+}
+
+// `∃even{(<= k)}`
+// `\(x: bool) = if k == 0 {id(x)} else {true_1(x)}`
+impl<T: Nat> ExPath for Even<T, LeK<T>> {
+    type Lift = IfK<T, EqK<T>, Id<bool>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: EqK {k: T::ZERO, i: ()}, tr: Id::default(), fa: (), i: ()}
+    }
+}
+
+// `∃odd{(<= k)}`
+// `\(x: bool) = if k == 0 {not(x)} else {true_1(x)}`
+impl<T: Nat> ExPath for Odd<T, LeK<T>> {
+    type Lift = IfK<T, EqK<T>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: EqK {k: T::ZERO, i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(= k){(> k2)}`
+// `\(x: bool) = if k2 == max {false_1(x)} else {if k > k2 {if k2 == max - 1 {id(x)} else {true_1(x)}} else {not(x)}}`
+impl<T: Nat> ExPath for EqK<T, GtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>, IfK<T, GtK<T>, IfK<T, EqK<T>, Id<bool>, ()>, Not>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {
+                k: self.k, co: GtK {k: self.i.k, i: ()}, fa: Not::default(), i: (),
+                tr: IfK {k: self.i.k, co: EqK {k: T::MAX - T::ONE, i: ()}, tr: Id::default(), fa: (), i: ()}
             }
         }
+    }
+}
 
-        // `∃if((> k), even, odd){(>= k2)}`
-        // `\(x: bool) = if k2 == max {not(x)} else {true_1(x)}`
-        impl ExPath for If<GeK<$t>, Even<$t>, Odd<$t>, GeK<$t>> {
-            type Lift = IfK<$t, EqK<$t>, Not, ()>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: EqK {k: $($max)*, i: ()}, tr: Not::default(), fa: (), i: ()}
+// `∃(= k){(<= k2)}`
+// `\(x: bool) = if k <= k2 {if k2 == 0 {id(x)} else {true_1(x)}} else {not(x)}`
+impl<T: Nat> ExPath for EqK<T, LeK<T>> {
+    type Lift = IfK<T, LeK<T>, IfK<T, EqK<T>, Id<bool>, ()>, Not>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: LeK {k: self.i.k, i: ()}, fa: Not::default(), i: (),
+            tr: IfK {k: self.i.k, co: EqK {k: T::ZERO, i: ()}, tr: Id::default(), fa: (), i: ()}
+        }
+    }
+}
+
+// `∃(>= k){(> k2)}`
+// `\(x: bool) = if k2 == max {false_1(x)} else {if k2 < k - 1 {true_1(x)} else {id(x)}}`
+// The `k - 1` constant is used as `0` for `k == 0`, which gives the same result.
+impl<T: Nat> ExPath for GeK<T, GtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>, IfK<T, LtK<T>, (), Id<bool>>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {k: self.i.k, co: LtK {k: self.k.saturating_sub(T::ONE), i: ()}, tr: (), fa: Id::default(), i: ()}
+        }
+    }
+}
+
+// `∃(>= k){(<= k2)}`
+// `\(x: bool) = if k == 0 {id(x)} else {if k <= k2 {true_1(x)} else {not(x)}}`
+impl<T: Nat> ExPath for GeK<T, LeK<T>> {
+    type Lift = IfK<T, EqK<T>, Id<bool>, IfK<T, LeK<T>, (), Not>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Id::default(), i: (),
+            fa: IfK {k: self.k, co: LeK {k: self.i.k, i: ()}, tr: (), fa: Not::default(), i: ()}
+        }
+    }
+}
+
+// `∃(< k){(> k2)} <=> not . ∃(>= k){(> k2)}`
+// `\(x: bool) = if k2 == max {false_1(x)} else {if k2 < k - 1 {true_1(x)} else {not(x)}}`
+impl<T: Nat> ExPath for LtK<T, GtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>, IfK<T, LtK<T>, (), Not>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: False1::default(), i: (),
+            fa: IfK {k: self.i.k, co: LtK {k: self.k.saturating_sub(T::ONE), i: ()}, tr: (), fa: Not::default(), i: ()}
+        }
+    }
+}
+
+// `∃(< k){(<= k2)} <=> not . ∃(>= k){(<= k2)}`
+// `\(x: bool) = if k == 0 {not(x)} else {if k <= k2 {true_1(x)} else {id(x)}}`
+impl<T: Nat> ExPath for LtK<T, LeK<T>> {
+    type Lift = IfK<T, EqK<T>, Not, IfK<T, LeK<T>, (), Id<bool>>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Not::default(), i: (),
+            fa: IfK {k: self.k, co: LeK {k: self.i.k, i: ()}, tr: (), fa: Id::default(), i: ()}
+        }
+    }
+}
+
+// `∃add(k){(> k2)} <=> (> k + k2)`
+// When `k + k2 >= max`, there is no valid input and `(> max)` is `false_1`.
+impl<T: Nat> ExPath for AddK<T, GtK<T>> {
+    type Lift = GtK<T>;
+    fn ex_path(&self) -> Self::Lift {
+        GtK {k: self.k.saturating_add(self.i.k), i: ()}
+    }
+}
+
+// `∃add(k){(<= k2)}`
+// `[k, k + k2]`, which covers `[k, max]` when `k + k2 >= max`.
+// `\(x: nat) = if k2 < max - k {[k, k + k2 + 1)(x)} else {(>= k)(x)}`
+// The `k + k2 + 1` constant saturates in the unused branch.
+impl<T: Nat> ExPath for AddK<T, LeK<T>> {
+    type Lift = IfK<T, LtK<T>, RangeK<T>, GeK<T>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: LtK {k: T::MAX - self.k, i: ()}, i: (),
+            tr: RangeK {lo: self.k, hi: self.k.saturating_add(self.i.k).saturating_add(T::ONE), i: ()},
+            fa: GeK {k: self.k, i: ()},
+        }
+    }
+}
+
+// `∃(!= k){even}`
+// `\(x: bool) = if even(k) {true_1(x)} else {id(x)}`
+impl<T: Nat> ExPath for NeqK<T, Even<T>> {
+    type Lift = IfK<T, Even<T>, (), Id<bool>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: Even::default(), tr: (), fa: Id::default(), i: ()}
+    }
+}
+
+// `∃(!= k){odd}`
+// `\(x: bool) = if odd(k) {true_1(x)} else {id(x)}`
+impl<T: Nat> ExPath for NeqK<T, Odd<T>> {
+    type Lift = IfK<T, Odd<T>, (), Id<bool>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: Odd::default(), tr: (), fa: Id::default(), i: ()}
+    }
+}
+
+// `∃(!= k){(= k2)}`
+// `\(x: bool) = if k == k2 {not(x)} else {id(x)}`
+impl<T: Nat> ExPath for NeqK<T, EqK<T>> {
+    type Lift = IfK<T, EqK<T>, Not, Id<bool>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: EqK {k: self.k, i: ()}, tr: Not::default(), fa: Id::default(), i: ()}
+    }
+}
+
+// `∃(!= k){(!= k2)}`
+// `\(x: bool) = if k == k2 {id(x)} else {true_1(x)}`
+impl<T: Nat> ExPath for NeqK<T, NeqK<T>> {
+    type Lift = IfK<T, EqK<T>, Id<bool>, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: EqK {k: self.k, i: ()}, tr: Id::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(!= k){(>= k2)} <=> not . ∃(= k){(>= k2)}`
+// `\(x: bool) = if k < k2 {id(x)} else {if k2 == max {if k == max {not(x)} else {true_1}} else {true_1}}`
+impl<T: Nat> ExPath for NeqK<T, GeK<T>> {
+    type Lift = IfK<T, LtK<T>, Id<bool>, IfK<T, EqK<T>, IfK<T, EqK<T>, Not, ()>, ()>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, i: (),
+            co: LtK {k: self.i.k, i: ()},
+            tr: Id::default(),
+            fa: IfK {k: self.i.k, co: EqK {k: T::MAX, i: ()},
+                tr: IfK {k: self.k, co: EqK {k: T::MAX, i: ()}, tr: Not::default(), fa: (), i: ()},
+                fa: (),
+                i: ()
+            },
+        }
+    }
+}
+
+// `∃(!= k){(< k2)} <=> not . ∃(= k){(< k2)}`
+// `\(x: bool) = if k2 == 0 {false_1(x)} else {if k < k2 {if k2 == 1 {not(x)} else {true_1(x)}} else {id(x)}}`
+impl<T: Nat> ExPath for NeqK<T, LtK<T>> {
+    type Lift = IfK<T, EqK<T>, False1<bool>, IfK<T, LtK<T>, IfK<T, EqK<T>, Not, ()>, Id<bool>>>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.i.k, co: EqK {k: T::ZERO, i: ()}, i: (),
+            tr: False1::default(),
+            fa: IfK {
+                k: self.k, co: LtK {k: self.i.k, i: ()}, fa: Id::default(), i: (),
+                tr: IfK {k: self.i.k, co: EqK {k: T::ONE, i: ()}, tr: Not::default(), fa: (), i: ()}
             }
         }
+    }
+}
 
-        // `∃odd{(>= k)}`
-        // `\(x: bool) = if k == max {id(x)} else {true_1(x)}`
-        impl ExPath for Odd<$t, GeK<$t>> {
-            type Lift = IfK<$t, EqK<$t>, Id<bool>, ()>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: EqK {k: $($max)*, i: ()}, tr: Id::default(), fa: (), i: ()}
+// There is more than one even and odd number that differs from `k`.
+ex_impl!{Nat Even<T, NeqK<T>>, ()}
+ex_impl!{Nat Odd<T, NeqK<T>>, ()}
+
+// `∃(= k){(!= k2)}`
+// `\(x: bool) = if k == k2 {not(x)} else {true_1(x)}`
+impl<T: Nat> ExPath for EqK<T, NeqK<T>> {
+    type Lift = IfK<T, EqK<T>, Not, ()>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {k: self.i.k, co: EqK {k: self.k, i: ()}, tr: Not::default(), fa: (), i: ()}
+    }
+}
+
+// `∃(>= k){(!= k2)}`
+// `k == 0 => id`
+// `k == 1 => if k2 == 0 {id} else {true_1}`
+// `k == max => if k2 == max {not} else {true_1}`
+// `\(x: bool) = if k == 0 {id(x)} else {if k == 1 {if k2 == 0 {id(x)} else {true_1(x)}} else {if k == max {if k2 == max {not(x)} else {true_1(x)}} else {true_1(x)}}}`
+impl<T: Nat> ExPath for GeK<T, NeqK<T>> {
+    type Lift = IfK<T, EqK<T>, Id<bool>, IfK<T, EqK<T>, IfK<T, EqK<T>, Id<bool>, ()>,
+                    IfK<T, EqK<T>, IfK<T, EqK<T>, Not, ()>, ()>
+                >>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Id::default(), i: (),
+            fa: IfK {
+                k: self.k, co: EqK {k: T::ONE, i: ()}, i: (),
+                tr: IfK {k: self.i.k, co: EqK {k: T::ZERO, i: ()}, tr: Id::default(), fa: (), i: ()},
+                fa: IfK {
+                    k: self.k, co: EqK {k: T::MAX, i: ()}, fa: (), i: (),
+                    tr: IfK {k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: Not::default(), fa: (), i: ()}
+                }
             }
         }
+    }
+}
 
-        // `∃(= k){>= k2}`
-        // `\(x: bool) = if k < k2 {not(x)} else {if k2 == max {if k == max {id(x)} else {true_1}} else {true_1}}`
-        impl ExPath for EqK<$t, GeK<$t>> {
-            type Lift = IfK<$t, LtK<$t>, Not, IfK<$t, EqK<$t>, IfK<$t, EqK<$t>, Id<bool>, ()>, ()>>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {
-                    k: self.k, i: (),
-                    co: LtK {k: self.i.k, i: ()},
-                    tr: Not::default(),
-                    fa: IfK {k: self.i.k, co: EqK {k: $($max)*, i: ()},
-                        tr: IfK {k: self.k, co: EqK {k: $($max)*, i: ()}, tr: Id::default(), fa: (), i: ()},
-                        fa: (),
-                        i: ()
-                    },
+// `∃(< k){(!= k2)} <=> not . ∃(>= k){(!= k2)}`
+// `\(x: bool) = if k == 0 {not(x)} else {if k == 1 {if k2 == 0 {not(x)} else {true_1(x)}} else {if k == max {if k2 == max {id(x)} else {true_1(x)}} else {true_1(x)}}}`
+impl<T: Nat> ExPath for LtK<T, NeqK<T>> {
+    type Lift = IfK<T, EqK<T>, Not, IfK<T, EqK<T>, IfK<T, EqK<T>, Not, ()>,
+                    IfK<T, EqK<T>, IfK<T, EqK<T>, Id<bool>, ()>, ()>
+                >>;
+    fn ex_path(&self) -> Self::Lift {
+        IfK {
+            k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: Not::default(), i: (),
+            fa: IfK {
+                k: self.k, co: EqK {k: T::ONE, i: ()}, i: (),
+                tr: IfK {k: self.i.k, co: EqK {k: T::ZERO, i: ()}, tr: Not::default(), fa: (), i: ()},
+                fa: IfK {
+                    k: self.k, co: EqK {k: T::MAX, i: ()}, fa: (), i: (),
+                    tr: IfK {k: self.i.k, co: EqK {k: T::MAX, i: ()}, tr: Id::default(), fa: (), i: ()}
                 }
             }
         }
+    }
+}
 
-        // `∃(< k){(>= k2)}`
-        // `\(x: bool) = if k2 >= k {not(x)} else {if k == 0 {not(x)} else {true_1}}`
-        impl ExPath for LtK<$t, GeK<$t>> {
-            type Lift = IfK<$t, GeK<$t>, Not, IfK<$t, EqK<$t>, Not, ()>>;
-            fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: GeK {k: self.k, i: ()}, tr: Not::default(),
-                     fa: IfK {k: self.k, co: EqK {k: 0, i: ()}, tr: Not::default(), fa: (), i: ()}, i: ()}
-            }
-        }
-
-        // `∃(>= k){(>= k2)}`
-        // `k2 == max => ∃(>= k){(>= max)}`
-        // `             ∃(>= k){(= max)} => k <= max`
-        // `             id`
-        // `k == 0 => ∃(>= 0){(>= k2)}`
-        // `          ∃true_1{(>= k2)}`
-        // `          ∃true_1`
-        // `          id`
-        // `k > k2 => true_1 else id
-        // `\(x: bool) = if k2 < k {true_1(x)} else {id(x)}`
-        impl ExPath for GeK<$t, GeK<$t>> {
-            type Lift = IfK<$t, LtK<$t>, (), Id<bool>>;
+// `∃if((>= k), even, odd){even} => ∃(>= k){even}`
+reduce_if_impl!{co: GeK<T>, tr: Even<T>, fa: Odd<T>, Even<T> => GeK<T>}
+// `∃if((>= k), even, odd){odd} => ∃(< k){odd}`
+reduce_if_impl!{co: GeK<T>, tr: Even<T>, fa: Odd<T>, Odd<T> => LtK<T>}
+// `∃if((>= k), odd, even){even} => ∃(< k){even}`
+reduce_if_impl!{co: GeK<T>, tr: Odd<T>, fa: Even<T>, Even<T> => LtK<T>}
+// `∃if((>= k), odd, even){odd} => ∃(>= k){odd}`
+reduce_if_impl!{co: GeK<T>, tr: Odd<T>, fa: Even<T>, Odd<T> => GeK<T>}
+// `∃if((< k), even, odd){even} => ∃(< k){even}`
+reduce_if_impl!{co: LtK<T>, tr: Even<T>, fa: Odd<T>, Even<T> => LtK<T>}
+// `∃if((< k), even, odd){odd} => ∃(>= k){odd}`
+reduce_if_impl!{co: LtK<T>, tr: Even<T>, fa: Odd<T>, Odd<T> => GeK<T>}
+// `∃if((< k), odd, even){even} => ∃(>= k){even}`
+reduce_if_impl!{co: LtK<T>, tr: Odd<T>, fa: Even<T>, Even<T> => GeK<T>}
+// `∃if((< k), odd, even){odd} => ∃(< k){odd}`
+reduce_if_impl!{co: LtK<T>, tr: Odd<T>, fa: Even<T>, Odd<T> => LtK<T>}
+
+// `∃f{¬a} <=> ∃f{a'}`, where `a'` is the complement of `a` in the dictionary.
+// `∃f{a ∨ b} <=> ∃f{a} ∨ ∃f{b}`, since the image of a union is the union of the images.
+// `∃f{(>= lo) ∧ (< hi)} <=> ∃f{[lo, hi)}`
+// The predicates are listed, because generic rules make inference of constraints overflow.
+macro_rules! combinator_impl {
+    (either $f:ident , [$($a:ident),*] , $bs:tt) => {$(
+        combinator_impl!{either $f, $a, $bs}
+    )*};
+    (either $f:ident , $a:ident , [$($b:ident),*]) => {$(
+        impl<T: Nat> ExPath for $f<T, Either<$a<T>, $b<T>>>
+            where $f<T, $a<T>>: ExPath, $f<T, $b<T>>: ExPath
+        {
+            type Lift = Either<<$f<T, $a<T>> as ExPath>::Lift, <$f<T, $b<T>> as ExPath>::Lift>;
             fn ex_path(&self) -> Self::Lift {
-                IfK {k: self.i.k, co: LtK {k: self.k, i: ()}, tr: (), fa: Id::default(), i: ()}
+                Either {a: self.i_force(self.i.a).ex_path(), b: self.i_force(self.i.b).ex_path(), i: ()}
             }
         }
+    )*};
+    ($f:ident , $($a:ident),*) => {$(
+        impl<T: Nat> ExPath for $f<T, Complement<$a<T>>>
+            where $f<T, <$a<T> as Negate>::Lift>: ExPath
+        {
+            type Lift = <$f<T, <$a<T> as Negate>::Lift> as ExPath>::Lift;
+            fn ex_path(&self) -> Self::Lift {self.i_force(self.i.a.negate()).ex_path()}
+        }
+    )*};
+    ($f:ident) => {
+        combinator_impl!{$f, Even, Odd, EqK, NeqK, GeK, LtK, GtK, LeK}
+        combinator_impl!{either $f, [Even, Odd, EqK, GeK, LtK], [Even, Odd, EqK, GeK, LtK]}
 
-        // `∃add(k){(>= k2)} <=> (>= k + k2)`
-        impl ExPath for AddK<$t, GeK<$t>> {
-            type Lift = GeK<$t>;
+        impl<T: Nat> ExPath for $f<T, Both<GeK<T>, LtK<T>>> {
+            type Lift = <$f<T, RangeK<T>> as ExPath>::Lift;
             fn ex_path(&self) -> Self::Lift {
-                GeK {k: self.k + self.i.k, i: ()}
+                self.i_force(RangeK {lo: self.i.a.k, hi: self.i.b.k, i: ()}).ex_path()
             }
         }
 
-        // `∃even{(< k)}`
-        // `k == 0 => ∃even{(< 0)} => false_1`
-        // `k == 1 => ∃even{(< 1)} => id`
-        // `k == 2 => ∃even{(< 2)} => true_1`
-        // `\(x: bool) = if k < 2 {if k == 0 {not(x)} else {id(x)}} else {true_1(x)}`
-        impl ExPath for Even<$t, LtK<$t>> {
-            type Lift = IfK<$t, LtK<$t>, IfK<$t, EqK<$t>, False1<bool>, Id<bool>>, ()>;
+        impl<T: Nat> ExPath for $f<T, Both<LtK<T>, GeK<T>>> {
+            type Lift = <$f<T, RangeK<T>> as ExPath>::Lift;
             fn ex_path(&self) -> Self::Lift {
-                IfK {
-                    k: self.i.k, co: LtK {k: 2, i: ()},
-                    tr: IfK {k: self.i.k, co: EqK {k: 0, i: ()}, tr: False1::default(), fa: Id::default(), i: ()},
-                    fa: (), i: ()
-                }
+                self.i_force(RangeK {lo: self.i.b.k, hi: self.i.a.k, i: ()}).ex_path()
             }
         }
+    };
+}
+
+combinator_impl!{AddK}
+combinator_impl!{Even}
+combinator_impl!{Odd}
+combinator_impl!{EqK}
+combinator_impl!{GeK}
+combinator_impl!{LtK}
+
+// `∃add{¬even} <=> ∃add{(odd, odd)}`
+impl<T: Nat> ExPath for Add<T, Complement<Even<T>>> {
+    type Lift = <Add<T, (Odd<T>, Odd<T>)> as ExPath>::Lift;
+    fn ex_path(&self) -> Self::Lift {self.i_force((Odd::default(), Odd::default())).ex_path()}
+}
+
+// `∃add{¬odd} <=> ∃add{(even, even)}`
+impl<T: Nat> ExPath for Add<T, Complement<Odd<T>>> {
+    type Lift = <Add<T, (Even<T>, Even<T>)> as ExPath>::Lift;
+    fn ex_path(&self) -> Self::Lift {self.i_force((Even::default(), Even::default())).ex_path()}
+}
 
-        // `∃if((< k), odd, even){(< k2)}`
-        // `k2 == 0 => ∃if((< k), odd, even){(< 0)} => false_1`
-        // `k2 == 1 => ∃if((< k), odd, even){(< 1)}`
-        // `           ∃if((< k), odd, even){(= 0)}`
-        // `           ∃if((< k), false, true)`
-        // `           ∃(>= k)`
-        // `k2 == 2 => ∃if((< k), odd, even){(< 2)} => true_1`
-        // `\(x: bool) = if k2 < 2 {if k2 == 0 {false_1(x)} else {(∃(>= k))(x)}} else {true_1(x)}`
-        impl ExPath for If<LtK<$t>, Odd<$t>, Even<$t>, LtK<$t>> {
-            type Lift = IfK<$t, LtK<$t>, IfK<$t, EqK<$t>, False1<bool>, <GeK<$t> as ExPath>::Lift>, ()>;
+// `∃add{a ∨ b} <=> ∃add{(a, a)} ∨ ∃add{(a, b)} ∨ ∃add{(b, a)} ∨ ∃add{(b, b)}`
+macro_rules! add_either_impl {
+    ($a:ident , $b:ident) => {
+        impl<T: Nat> ExPath for Add<T, Either<$a<T>, $b<T>>> {
+            type Lift = Either<
+                Either<<Add<T, ($a<T>, $a<T>)> as ExPath>::Lift, <Add<T, ($a<T>, $b<T>)> as ExPath>::Lift>,
+                Either<<Add<T, ($b<T>, $a<T>)> as ExPath>::Lift, <Add<T, ($b<T>, $b<T>)> as ExPath>::Lift>
+            >;
             fn ex_path(&self) -> Self::Lift {
-                IfK {
-                    k: self.i.k, co: LtK {k: 2, i: ()}, i: (),
-                    tr: IfK {k: self.i.k, co: EqK {k: 0, i: ()}, tr: False1::default(),
-                                fa: GeK {k: self.co.k, i: ()}.ex_path(), i: ()},
-                    fa: ()
+                let (a, b) = (self.i.a, self.i.b);
+                Either {
+                    a: Either {a: self.i_force((a, a)).ex_path(), b: self.i_force((a, b)).ex_path(), i: ()},
+                    b: Either {a: self.i_force((b, a)).ex_path(), b: self.i_force((b, b)).ex_path(), i: ()},
+                    i: (),
                 }
             }
         }
+    };
+}
 
-        // `∃if((< k), even, odd){(< k2)}`
-        // `k2 == 0 => ∃if((< k), even, odd){(< 0)} => false_1`
-        // `k2 == 1 => ∃if((< k), even, odd){(< 1)}`
-        // `           ∃if((< k), even, odd){(= 0)}`
-        // `           ∃if((< k), true, false)`
-        // `           ∃(< k)`
-        // `k2 == 2 => ∃if((< k), even, odd){(< 2)} => true_1`
-        // `\(x: bool) = if k2 < 2 {if k2 == 0 {false_1(x)} else {(∃(< k))(x)}} else {true_1(x)}`
-        impl ExPath for If<LtK<$t>, Even<$t>, Odd<$t>, LtK<$t>> {
-            type Lift = IfK<$t, LtK<$t>, IfK<$t, EqK<$t>, False1<bool>, <LtK<$t> as ExPath>::Lift>, ()>;
+add_either_impl!{Even, Odd}
+add_either_impl!{Odd, Even}
+
+// `∃even{even ∧ b} <=> id ∧ ∃b{even}`
+// `∃odd{odd ∧ b} <=> id ∧ ∃b{odd}`
+// The output `true` is reached when some number with the parity satisfies `b`.
+// `∃even{odd ∧ b} <=> not ∧ (∃b{odd} . not)`
+// `∃odd{even ∧ b} <=> not ∧ (∃b{even} . not)`
+// The output `false` is reached when some number with the other parity satisfies `b`.
+macro_rules! parity_both_impl {
+    (id $f:ident , $p:ident , $b:ident) => {
+        impl<T: Nat> ExPath for $f<T, Both<$p<T>, $b<T>>>
+            where $b<T, $p<T>>: ExPath
+        {
+            type Lift = Both<Id<bool>, <$b<T, $p<T>> as ExPath>::Lift>;
             fn ex_path(&self) -> Self::Lift {
-                IfK {
-                    k: self.i.k, co: LtK {k: 2, i: ()}, i: (),
-                    tr: IfK {k: self.i.k, co: EqK {k: 0, i: ()}, tr: False1::default(),
-                             fa: LtK {k: self.co.k, i: ()}.ex_path(), i: ()},
-                    fa: ()
-                }
+                Both {a: Id::default(), b: self.i.b.i_force(self.i.a).ex_path(), i: ()}
             }
         }
-
-        // `∃if((>= k), odd, even){(< k2)}`
-        // `k2 == 0 => ∃if((>= k), odd, even){(< 0)} => false_1`
-        // `k2 == 1 => ∃if((>= k), odd, even){(< 1)}`
-        // `           ∃if((>= k), odd, even){(= 0)}`
-        // `           ∃if((>= k), false, true)`
-        // `           ∃(< k)`
-        // `k2 == 2 => ∃if((>= k), odd, even){(< 2)} => true_1`
-        // `\(x: bool) = if k2 < 2 {if k2 == 0 {false_1(x)} else {(∃(< k))(x)}} else {true_1}`
-        impl ExPath for If<GeK<$t>, Odd<$t>, Even<$t>, LtK<$t>> {
-            type Lift = IfK<$t, LtK<$t>, IfK<$t, EqK<$t>, False1<bool>, <LtK<$t> as ExPath>::Lift>, ()>;
+        parity_both_impl!{swap $f, $p, $b}
+    };
+    (not $f:ident , $p:ident , $b:ident) => {
+        impl<T: Nat> ExPath for $f<T, Both<$p<T>, $b<T>>>
+            where $b<T, $p<T>>: ExPath
+        {
+            type Lift = Both<Not, Compose<<$b<T, $p<T>> as ExPath>::Lift, Not>>;
             fn ex_path(&self) -> Self::Lift {
-                IfK {
-                    k: self.i.k, co: LtK {k: 2, i: ()}, i: (),
-                    tr: IfK {k: self.i.k, co: EqK {k: 0, i: ()}, tr: False1::default(),
-                             fa: LtK {k: self.co.k, i: ()}.ex_path(), i: ()},
-                    fa: ()
+                Both {
+                    a: Not::default(),
+                    b: Compose {g: self.i.b.i_force(self.i.a).ex_path(), f: Not::default()},
+                    i: (),
                 }
             }
         }
-
-        // `∃if((>= k), even, odd){(< k2)}`
-        // `k2 == 0 => ∃if((>= k), even, odd){(< 0)} => false_1`
-        // `k2 == 1 => ∃if((>= k), even, odd){(< 1)}`
-        // `           ∃if((>= k), even, odd){(= 0)}`
-        // `           ∃if((>= k), true, false)`
-        // `           ∃(>= k)`
-        // `k2 == 2 => ∃if((>= k), even, odd){(< 2)} => true_1`
-        // `\(x: bool) = if k2 < 2 {if k == 0 {false_1(x)} else {(∃(>= k))(x)}} else {true_1(x)}`
-        impl ExPath for If<GeK<$t>, Even<$t>, Odd<$t>, LtK<$t>> {
-            type Lift = IfK<$t, LtK<$t>, IfK<$t, EqK<$t>, False1<bool>, <GeK<$t> as ExPath>::Lift>, ()>;
+        parity_both_impl!{swap $f, $p, $b}
+    };
+    // `∃f{b ∧ a} <=> ∃f{a ∧ b}`
+    (swap $f:ident , $p:ident , $b:ident) => {
+        impl<T: Nat> ExPath for $f<T, Both<$b<T>, $p<T>>>
+            where $f<T, Both<$p<T>, $b<T>>>: ExPath
+        {
+            type Lift = <$f<T, Both<$p<T>, $b<T>>> as ExPath>::Lift;
             fn ex_path(&self) -> Self::Lift {
-                IfK {
-                    k: self.i.k, co: LtK {k: 2, i: ()}, i: (),
-                    tr: IfK {k: self.i.k, co: EqK {k: 0, i: ()}, tr: False1::default(),
-                             fa: GeK {k: self.co.k, i: ()}.ex_path(), i: ()},
-                    fa: ()
-                }
+                self.i_force(Both {a: self.i.b, b: self.i.a, i: ()}).ex_path()
             }
         }
+    };
+}
+
+parity_both_impl!{id Even, Even, EqK}
+parity_both_impl!{id Even, Even, GeK}
+parity_both_impl!{id Even, Even, LtK}
+parity_both_impl!{id Odd, Odd, EqK}
+parity_both_impl!{id Odd, Odd, GeK}
+parity_both_impl!{id Odd, Odd, LtK}
+parity_both_impl!{not Even, Odd, EqK}
+parity_both_impl!{not Even, Odd, GeK}
+parity_both_impl!{not Even, Odd, LtK}
+parity_both_impl!{not Odd, Even, EqK}
+parity_both_impl!{not Odd, Even, GeK}
+parity_both_impl!{not Odd, Even, LtK}
 
-        // `∃odd{(< k)}`
-        // `k == 0 => ∃odd{(< 0)} => false_1`
-        // `k == 1 => ∃odd{(< 1)} => ∃odd{(= 0)} => not`
-        // `k == 2 => ∃odd{(< 2)} => true_1`
-        // `\(x: bool) = if k < 2 {if k == 0 {false_1(x)} else {not(x)}} else {true_1}`
-        impl ExPath for Odd<$t, LtK<$t>> {
-            type Lift = IfK<$t, LtK<$t>, IfK<$t, EqK<$t>, False1<bool>, Not>, ()>;
+// The bounds `(= k)`, `(>= k)` and `(< k)` as closed intervals `[lo, hi]`.
+// An empty interval has `lo > hi`.
+trait Interval<T> {
+    fn interval(&self) -> (T, T);
+}
+
+impl<T: Nat> Interval<T> for EqK<T> {
+    fn interval(&self) -> (T, T) {(self.k, self.k)}
+}
+
+impl<T: Nat> Interval<T> for GeK<T> {
+    fn interval(&self) -> (T, T) {(self.k, T::MAX)}
+}
+
+impl<T: Nat> Interval<T> for LtK<T> {
+    fn interval(&self) -> (T, T) {
+        match self.k.checked_sub(T::ONE) {
+            Some(hi) => (T::ZERO, hi),
+            None => (T::ONE, T::ZERO),
+        }
+    }
+}
+
+// The smallest and largest numbers with the parity in an interval,
+// or an empty interval when there is no such number.
+fn parity_hull<T: Nat>(odd: bool, (lo, hi): (T, T)) -> (T, T) {
+    let is_odd = |x: T| x % T::from(2) == T::ONE;
+    let lo = if is_odd(lo) == odd {Some(lo)} else {lo.checked_add(T::ONE)};
+    let hi = if is_odd(hi) == odd {Some(hi)} else {hi.checked_sub(T::ONE)};
+    match (lo, hi) {
+        (Some(lo), Some(hi)) => (lo, hi),
+        _ => (T::ONE, T::ZERO),
+    }
+}
+
+// A parity `p` and a bound `b` constrain the input to the numbers with parity `p` in `[lo, hi]`,
+// where `lo` and `hi` are the smallest and largest of them.
+// `∃(= k){p ∧ b}`: `true` when `k` is in the set, `false` unless the set is `{k}`.
+// `∃(>= k){p ∧ b}`: `true` when `hi >= k`, `false` when `lo < k`.
+// `∃(< k){p ∧ b}`: `true` when `lo < k`, `false` when `hi >= k`.
+// `∃add(k){p ∧ b} <=> ∃add(k){p} ∧ ∃add(k){b}`, since `add(k)` is injective.
+// `∃add{p ∧ b} <=> even ∧ [2 * lo, 2 * hi]`, where the sum of two numbers in `[lo, hi]`
+// with the same parity is any even number in `[2 * lo, 2 * hi]`, except those that overflow.
+// Constants saturate in the unused branches.
+macro_rules! parity_bound_impl {
+    ($p:ident , $odd:expr , $b:ident) => {
+        impl<T: Nat> ExPath for EqK<T, Both<$p<T>, $b<T>>> {
+            type Lift = IfK<T, LeK<T>,
+                IfK<T, Both<$p<T>, Both<GeK<T>, LeK<T>>>, IfK<T, EqK<T>, Id<bool>, ()>, Not>,
+                False1<bool>>;
             fn ex_path(&self) -> Self::Lift {
+                let (lo, hi) = parity_hull($odd, self.i.b.interval());
                 IfK {
-                    k: self.i.k, co: LtK {k: 2, i: ()}, i: (),
-                    tr: IfK {k: self.i.k, co: EqK {k: 0, i: ()}, tr: False1::default(),
-                             fa: Not::default(), i: ()},
-                    fa: ()
+                    k: lo, co: LeK {k: hi, i: ()}, fa: False1::default(), i: (),
+                    tr: IfK {
+                        k: self.k, i: (),
+                        co: Both::new($p::default(), Both::new(GeK {k: lo, i: ()}, LeK {k: hi, i: ()})),
+                        tr: IfK {k: lo, co: EqK {k: hi, i: ()}, tr: Id::default(), fa: (), i: ()},
+                        fa: Not::default(),
+                    },
                 }
             }
         }
 
-        // `∃(= k){(< k2)}`
-        // `k2 == 0 => false_1`
-        // `k2 >= k => not`
-        //          else true_1`
-        // `\(x: bool) = if k2 == 0 {false_1(x)} else {if k2 >= k {not(x)} else {true_1(x)}}`
-        impl ExPath for EqK<$t, LtK<$t>> {
-            type Lift = IfK<$t, EqK<$t>, False1<bool>, IfK<$t, GeK<$t>, Not, ()>>;
+        impl<T: Nat> ExPath for GeK<T, Both<$p<T>, $b<T>>> {
+            type Lift = IfK<T, LeK<T>,
+                IfK<T, LtK<T>, IfK<T, GeK<T>, (), Not>, Id<bool>>,
+                False1<bool>>;
             fn ex_path(&self) -> Self::Lift {
+                let (lo, hi) = parity_hull($odd, self.i.b.interval());
                 IfK {
-                    k: self.i.k, co: EqK {k: 0, i: ()}, i: (),
-                    tr: False1::default(),
-                    fa: IfK {k: self.i.k, co: GeK {k: self.k, i: ()}, tr: Not::default(), fa: (), i: ()}
+                    k: lo, co: LeK {k: hi, i: ()}, fa: False1::default(), i: (),
+                    tr: IfK {
+                        k: lo, co: LtK {k: self.k, i: ()}, fa: Id::default(), i: (),
+                        tr: IfK {k: hi, co: GeK {k: self.k, i: ()}, tr: (), fa: Not::default(), i: ()},
+                    },
                 }
             }
         }
 
-        // `∃(< k){(< k2)}`
-        // `k2 == 0 => `∃(< k){(< 0)} => false_1`
-        // `k == 0` => not
-        // `k >= k2 => id else true_1`
-        // `\(x: bool) = if k2 == 0 {false_1(x)} else {if k == 0 {not(x)} else {if k >= k2 {id(x)} else {true_1(x)}}}`
-        impl ExPath for LtK<$t, LtK<$t>> {
-            type Lift = IfK<$t, EqK<$t>, False1<bool>,
-                            IfK<$t, EqK<$t>, Not, IfK<$t, GeK<$t>, Id<bool>, ()>>
-                        >;
+        impl<T: Nat> ExPath for LtK<T, Both<$p<T>, $b<T>>> {
+            type Lift = IfK<T, LeK<T>,
+                IfK<T, LtK<T>, IfK<T, GeK<T>, (), Id<bool>>, Not>,
+                False1<bool>>;
             fn ex_path(&self) -> Self::Lift {
+                let (lo, hi) = parity_hull($odd, self.i.b.interval());
                 IfK {
-                    k: self.i.k, co: EqK {k: 0, i: ()}, i: (),
-                    tr: False1::default(),
-                    fa: IfK {
-                        k: self.k, co: EqK {k: 0, i: ()}, tr: Not::default(),
-                        fa: IfK {k: self.k, co: GeK {k: self.i.k, i: ()},
-                                 tr: Id::default(), fa: (), i: ()}, i: ()
-                    }
+                    k: lo, co: LeK {k: hi, i: ()}, fa: False1::default(), i: (),
+                    tr: IfK {
+                        k: lo, co: LtK {k: self.k, i: ()}, fa: Not::default(), i: (),
+                        tr: IfK {k: hi, co: GeK {k: self.k, i: ()}, tr: (), fa: Id::default(), i: ()},
+                    },
                 }
             }
         }
 
-        // `∃(>= k){(< k2)}`
-        // `k2 == 0 => false_1`
-        // `k == 0 => id else true_1`
-        // `\(x: bool) = if k2 == 0 {false_1(x)} else {if k == 0 {id(x)} else {true_1(x)}}`
-        impl ExPath for GeK<$t, LtK<$t>> {
-            type Lift = IfK<$t, EqK<$t>, False1<bool>, IfK<$t, EqK<$t>, Id<bool>, ()>>;
+        impl<T: Nat> ExPath for AddK<T, Both<$p<T>, $b<T>>> {
+            type Lift = Both<<AddK<T, $p<T>> as ExPath>::Lift, <AddK<T, $b<T>> as ExPath>::Lift>;
             fn ex_path(&self) -> Self::Lift {
-                IfK {
-                    k: self.i.k, co: EqK {k: 0, i: ()}, tr: False1::default(), i: (),
-                    fa: IfK {k: self.k, co: EqK {k: 0, i: ()}, tr: Id::default(), fa: (), i: ()}
-                }
+                Both::new(self.i_force(self.i.a).ex_path(), self.i_force(self.i.b).ex_path())
             }
         }
 
-        // `∃add(k){(< k2)}`
-        // `k2 == 0 => false_1`
-        // `[k, k + k2)`
-        // `\(x: nat) = if k2 == 0 {false_1(x)} else {if x >= k {(< k + k2)(x)} else {false_1(x)}}`
-        impl ExPath for AddK<$t, LtK<$t>> {
-            type Lift = IfK<$t, EqK<$t>, False1<$t>, If<GeK<$t>, LtK<$t>, False1<$t>>>;
+        impl<T: Nat> ExPath for Add<T, Both<$p<T>, $b<T>>> {
+            type Lift = IfK<T, LeK<T>, Both<Even<T>, Both<GeK<T>, LeK<T>>>, False1<T>>;
             fn ex_path(&self) -> Self::Lift {
+                let (lo, hi) = parity_hull($odd, self.i.b.interval());
+                let two = T::from(2);
                 IfK {
-                    k: self.i.k, co: EqK {k: 0, i: ()}, i: (), tr: False1::default(),
-                    fa: If {co: GeK {k: self.k, i: ()}, tr: LtK {k: self.k + self.i.k, i: ()},
-                            fa: False1::default(), i: ()}
+                    k: lo, co: LeK {k: hi.min(T::MAX / two), i: ()}, fa: False1::default(), i: (),
+                    tr: Both::new(Even::default(), Both::new(GeK {k: lo.saturating_mul(two), i: ()},
+                                                             LeK {k: hi.saturating_mul(two), i: ()})),
                 }
             }
         }
 
-        // `∃if((>= k), even, odd){even} => ∃(>= k)`
-        reduce_if_impl!{co: GeK<$t>, tr: Even<$t>, fa: Odd<$t>, Even<$t> => GeK<$t>}
-        // `∃if((>= k), even, odd){odd} => ∃(< k)`
-        reduce_if_impl!{co: GeK<$t>, tr: Even<$t>, fa: Odd<$t>, Odd<$t> => LtK<$t>}
-        // `∃if((>= k), odd, even){even} => ∃(< k)`
-        reduce_if_impl!{co: GeK<$t>, tr: Odd<$t>, fa: Even<$t>, Even<$t> => LtK<$t>}
-        // `∃if((>= k), odd, even){odd} => ∃(>= k)`
-        reduce_if_impl!{co: GeK<$t>, tr: Odd<$t>, fa: Even<$t>, Odd<$t> => GeK<$t>}
-        // `∃if((< k), even, odd){even} => ∃(< k)`
-        reduce_if_impl!{co: LtK<$t>, tr: Even<$t>, fa: Odd<$t>, Even<$t> => LtK<$t>}
-        // `∃if((< k), even, odd){odd} => ∃(>= k)`
-        reduce_if_impl!{co: LtK<$t>, tr: Even<$t>, fa: Odd<$t>, Odd<$t> => GeK<$t>}
-        // `∃if((< k), odd, even){even} => ∃(>= k)`
-        reduce_if_impl!{co: LtK<$t>, tr: Odd<$t>, fa: Even<$t>, Even<$t> => GeK<$t>}
-        // `∃if((< k), odd, even){odd} => ∃(< k)`
-        reduce_if_impl!{co: LtK<$t>, tr: Odd<$t>, fa: Even<$t>, Odd<$t> => LtK<$t>}
-
+        parity_bound_impl!{swap EqK, $p, $b}
+        parity_bound_impl!{swap GeK, $p, $b}
+        parity_bound_impl!{swap LtK, $p, $b}
+        parity_bound_impl!{swap AddK, $p, $b}
+        parity_bound_impl!{swap Add, $p, $b}
+    };
+    // `∃f{b ∧ p} <=> ∃f{p ∧ b}`
+    (swap $f:ident , $p:ident , $b:ident) => {
+        impl<T: Nat> ExPath for $f<T, Both<$b<T>, $p<T>>> {
+            type Lift = <$f<T, Both<$p<T>, $b<T>>> as ExPath>::Lift;
+            fn ex_path(&self) -> Self::Lift {
+                self.i_force(Both {a: self.i.b, b: self.i.a, i: ()}).ex_path()
+            }
+        }
     };
 }
 
-nat_impl!{u8, max: ::std::u8::MAX}
-nat_impl!{u16, max: ::std::u16::MAX}
-nat_impl!{u32, max: ::std::u32::MAX}
-nat_impl!{u64, max: ::std::u64::MAX}
+parity_bound_impl!{Even, false, EqK}
+parity_bound_impl!{Even, false, GeK}
+parity_bound_impl!{Even, false, LtK}
+parity_bound_impl!{Odd, true, EqK}
+parity_bound_impl!{Odd, true, GeK}
+parity_bound_impl!{Odd, true, LtK}
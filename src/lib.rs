@@ -80,9 +80,20 @@
 //! All objects in this library are higher order representations of constrained functions.
 //! This means they do not "compute" but merely construct types of each other, in a way that Rust can type check.
 //!
+//! The same objects can also be evaluated with the `Eval` trait, such that you can run the functions you prove things about.
+//!
 //! - `Constrain` trait (type `.i(<constrain>)`, `.i_force` skips existential path check)
 //! - `ExPath` trait (type `.ex_path()`)
-//! - `Path` trait (type `.path()`, `.path_force` skips existential path check)
+//! - `Path` trait (type `.path()`, `.try_path` returns `PathError` instead of panicking, `.path_force` skips existential path check)
+//! - `PathTo` trait (type `.path_to(g_in, g_out)`, `.try_path_to` returns `PathError`) checks asymmetric paths `f[g_in -> g_out] <=> h`
+//! - `PathArgs` trait (type `.path_args(g0, g1, g2)`, `.try_path_args` returns `PathError`) checks binary paths `f[(g0, g1) -> g2] <=> h` with a property per argument
+//! - `Eval` trait (type `.eval(<input>)`, returns `None` when input does not satisfy the constraint)
+//! - `verify` compares existential paths with the image of a function by evaluating all inputs
+//! - `Compose` (type `Compose::new(g, f)`) is `g . f`, with `∃(g . f) <=> ∃g{∃f}` and `(g . f)[h] <=> g[h] . f[h]`
+//! - `Both` (`a ∧ b`), `Either` (`a ∨ b`) and `Complement` (`¬a`) combine predicates, `Union` (type `Union::new(if_)`) gives `∃if(co, tr, fa){i} <=> ∃tr{co ∧ i} ∨ ∃fa{¬co ∧ i}`;
+//!   a plain `If` only has an `ExPath` for the combinations with a simpler rule, so wrap it in `Union::new` for any other `If`
+//! - `EqC`, `GeC`, `LtC` and `AddC` put the constant in the type, but only `∃add(K) => (>= K)` is checked by the compiler;
+//!   lifts that depend on the value of a constant, e.g. `add(K)[even]` or `∃add(K){(= K2)} => (= K + K2)`, capture it at runtime
 //! - Complete Boolean algebra (all paths checked with all constraints)
 //! - Some work on natural numbers
 //!
@@ -101,12 +112,23 @@ pub use constrain::*;
 pub use tri_path::*;
 pub use ex_path::*;
 pub use path::*;
+pub use eval::*;
+pub use verify::*;
+pub use nat::*;
+pub use big_nat::*;
+pub use dynamic::*;
+pub use display::*;
 
 mod constrain;
 mod tri_path;
 mod ex_path;
 mod path;
+mod eval;
+mod verify;
 mod display;
+mod nat;
+mod big_nat;
+mod dynamic;
 
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct False1<T, I = ()> {t: PhantomData<T>, i: I}
@@ -129,13 +151,12 @@ pub struct Xor<I = ()> {i: I}
 pub struct Nor<I = ()> {i: I}
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct Nand<I = ()> {i: I}
-/* TODO: To be implemented later.
-/// Checks for non-equivalence. Use `Xor` for booleans.
+/// Checks for non-equivalence. Same as `Xor` for booleans.
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct Neq<T, I = ()> {t: PhantomData<T>, i: I}
+/// `(!= k)`
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct NeqK<T, I = ()> {k: T, i: I}
-*/
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct Exc<I = ()> {i: I}
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
@@ -149,11 +170,66 @@ pub struct Nexc<I = ()> {i: I}
 pub struct Even<T, I = ()> {t: PhantomData<T>, i: I}
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct Odd<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(x) = x % m == r`, where `(mod 0)` is equality, such that `even <=> (% 2 = 0)`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct ModK<T, I = ()> {m: T, r: T, i: I}
 
+/// `\(a, b) = a + b`, which is only defined when the sum does not overflow, like `checked_add`.
+/// The trivial path returns the domain `add_fits` for fixed-size integers.
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct Add<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(x) = x + k`, which is only defined when the sum does not overflow.
+/// The trivial path returns the domain `(<= max - k)` for natural numbers.
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct AddK<T, I = ()> {k: T, i: I}
+/// `\(a, b) = a + b`, which wraps around to `0` after `max`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct WrappingAdd<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(a, b) = a + b`, which is only defined when the sum is at most `max`.
+/// The trivial path returns the domain `add_fits`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct CheckedAdd<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(a, b) = a + b`, which stays at `max` when the sum is greater.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct SaturatingAdd<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(a, b) = a + b <= max`, or `min <= a + b <= max` for signed integers.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct AddFits<T, I = ()> {t: PhantomData<T>, i: I}
+
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Mul<T, I = ()> {t: PhantomData<T>, i: I}
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct MulK<T, I = ()> {k: T, i: I}
+
+/// Division, which is only defined when dividing by a number other than zero.
+/// The trivial path returns the domain `(true_1, (!= 0))`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Div<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(x) = x / k`, which is not defined for `k == 0`.
+/// The trivial path returns the domain `false_1` for `k == 0`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct DivK<T, I = ()> {k: T, i: I}
+/// Remainder, which is only defined when dividing by a number other than zero.
+/// The trivial path returns the domain `(true_1, (!= 0))`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Rem<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(x) = x % k`, which is not defined for `k == 0`.
+/// The trivial path returns the domain `false_1` for `k == 0`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct RemK<T, I = ()> {k: T, i: I}
+/// `\(a, b) = (a + b) % k`, which is not defined for `k == 0`.
+/// Unlike `add`, this never overflows.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct AddMod<T, I = ()> {k: T, i: I}
+
+/// Subtraction, which is only defined for `a >= b`.
+/// The trivial path returns the domain `ge`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Sub<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(x) = x - k`, which is only defined for `x >= k`.
+/// The trivial path returns the domain `(>= k)`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct SubK<T, I = ()> {k: T, i: I}
 
 /// `(>= k)`
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
@@ -161,6 +237,62 @@ pub struct GeK<T, I = ()> {k: T, i: I}
 /// `(< k)`
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct LtK<T, I = ()> {k: T, i: I}
+/// `(> k)`
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct GtK<T, I = ()> {k: T, i: I}
+/// `(<= k)`
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct LeK<T, I = ()> {k: T, i: I}
+/// `[lo, hi)`, i.e. `\(x) = lo <= x && x < hi`
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct RangeK<T, I = ()> {lo: T, hi: T, i: I}
+/// `\(a, b) = a >= b`
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Ge<T, I = ()> {t: PhantomData<T>, i: I}
+
+/// `(= K)`, where the constant is part of the type.
+/// Existential paths that do not depend on the value of `K` are checked by the compiler,
+/// while the others use the rules of `EqK` and are compared at runtime.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct EqC<const K: u64, I = ()> {i: I}
+/// `(>= K)`, where the constant is part of the type.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct GeC<const K: u64, I = ()> {i: I}
+/// `(< K)`, where the constant is part of the type.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct LtC<const K: u64, I = ()> {i: I}
+/// `\(x) = x + K`, where the constant is part of the type.
+/// `∃add(K) => (>= K)` is checked by the compiler.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct AddC<const K: u64, I = ()> {i: I}
+
+/// `\(x) = -x`, which is not defined for `min`.
+/// The trivial path returns the domain `(!= min)`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Neg<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(x) = |x|`, which is not defined for `min`.
+/// The trivial path returns the domain `(!= min)`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Abs<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(x) = x < 0`
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct IsNeg<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(x) = x > 0`
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct IsPos<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(x) = x != 0`
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct NonZero<T, I = ()> {t: PhantomData<T>, i: I}
+
+/// `\(x) = x != x`
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct IsNan<T, I = ()> {t: PhantomData<T>, i: I}
+/// `\(x) = !is_nan(x) && !is_inf(x)`
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct IsFinite<T, I = ()> {t: PhantomData<T>, i: I}
+/// Returns `true` when the sign bit is set, which includes `-0.0` and negative NaNs.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct SignBit<T, I = ()> {t: PhantomData<T>, i: I}
 
 /// `\(x) = if co(k) {tr(x)} else {fa(x)}`
 /// Technically this could reduce the condition to a `bool`,
@@ -170,8 +302,55 @@ pub struct IfK<T, Co, Tr, Fa, I = ()> {k: T, co: Co, tr: Tr, fa: Fa, i: I}
 /// `\(x) = if co(x) {tr(x)} else {fa(x)}`
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct If<Co, Tr, Fa, I = ()> {co: Co, tr: Tr, fa: Fa, i: I}
+/// `\(x) = a(x) ∧ b(x)`, the intersection of two predicates.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Both<A, B, I = ()> {a: A, b: B, i: I}
+
+impl<A, B> Both<A, B> {
+    /// Creates `a ∧ b`.
+    pub fn new(a: A, b: B) -> Both<A, B> {Both {a, b, i: ()}}
+}
+
+/// `\(x) = a(x) ∨ b(x)`, the union of two predicates.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Either<A, B, I = ()> {a: A, b: B, i: I}
+
+impl<A, B> Either<A, B> {
+    /// Creates `a ∨ b`.
+    pub fn new(a: A, b: B) -> Either<A, B> {Either {a, b, i: ()}}
+}
+
+/// `\(x) = ¬a(x)`, the complement of a predicate.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Complement<A, I = ()> {a: A, i: I}
+
+impl<A> Complement<A> {
+    /// Creates `¬a`.
+    pub fn new(a: A) -> Complement<A> {Complement {a, i: ()}}
+}
+
+/// Same function as `f`, but the existential path is the union of the images of the branches.
+/// This gives an existential path to any `If`, also when there is no simpler rule.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Union<F> {f: F}
+
+impl<F> Union<F> {
+    /// Creates `union(f)`.
+    pub fn new(f: F) -> Union<F> {Union {f}}
+}
+
+/// `\(x) = g(f(x))`, written `g . f`.
+/// The input constraint is the one of `f`.
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct Compose<G, F> {g: G, f: F}
+
+impl<G, F> Compose<G, F> {
+    /// Creates `g . f`.
+    pub fn new(g: G, f: F) -> Compose<G, F> {Compose {g, f}}
+}
 
 #[cfg(test)]
+#[allow(clippy::let_unit_value)]
 mod tests {
     use super::*;
 
@@ -250,14 +429,13 @@ mod tests {
         };
     );
 
-    fn test_nat<T, U: From<u8>>(a: T)
+    fn test_nat<T, U: From<u8> + Default>(a: T)
         where T: ExPath + Constrain<()> +
                  Constrain<Even<U>> + Constrain<Odd<U>> +
                  Constrain<EqK<U>> + Constrain<GeK<U>> +
                  Constrain<LtK<U>>,
               <T as ExPath>::Lift: ExPath,
               <<T as ExPath>::Lift as ExPath>::Lift: ExPath,
-              U: Default,
               <T as Constrain<()>>::Lift: ExPath,
               <T as Constrain<Even<U>>>::Lift: ExPath,
               <T as Constrain<Odd<U>>>::Lift: ExPath,
@@ -283,13 +461,13 @@ mod tests {
         let b = a.i(Odd::default()).ex_path();
         let c = b.ex_path();
         let _ = c.ex_path();
-        let b = a.i(EqK {k: (2 as u8).into(), i: ()}).ex_path();
+        let b = a.i(EqK {k: 2u8.into(), i: ()}).ex_path();
         let c = b.ex_path();
         let _ = c.ex_path();
-        let b = a.i(GeK {k: (2 as u8).into(), i: ()}).ex_path();
+        let b = a.i(GeK {k: 2u8.into(), i: ()}).ex_path();
         let c = b.ex_path();
         let _ = c.ex_path();
-        let _ = a.i(LtK {k: (2 as u8).into(), i: ()}).ex_path();
+        let _ = a.i(LtK {k: 2u8.into(), i: ()}).ex_path();
     }
 
     macro_rules! test_nat (
@@ -301,6 +479,9 @@ mod tests {
             let ge_2: GeK<Nat> = add_2.ex_path();
             let lt_2: LtK<Nat> = LtK {k: 2, i: ()};
             let eq_2: EqK<Nat> = EqK {k: 2, i: ()};
+            let neq_2: NeqK<Nat> = NeqK {k: 2, i: ()};
+            let gt_2: GtK<Nat> = GtK {k: 2, i: ()};
+            let le_2: LeK<Nat> = LeK {k: 2, i: ()};
             let if_ge_2_even_odd = If {co: ge_2, tr: even, fa: odd, i: ()};
             let if_ge_2_odd_even = If {co: ge_2, tr: odd, fa: even, i: ()};
             let if_lt_2_even_odd = If {co: lt_2, tr: even, fa: odd, i: ()};
@@ -312,6 +493,9 @@ mod tests {
             test_nat(ge_2);
             test_nat(lt_2);
             test_nat(eq_2);
+            test_nat(neq_2);
+            test_nat(gt_2);
+            test_nat(le_2);
             test_nat(even);
             test_nat(odd);
             test_nat(if_ge_2_even_odd);
@@ -337,6 +521,7 @@ mod tests {
         let or: Or = Or::default();
         let eq_bool: Eq<bool> = Eq::default();
         let xor: Xor = Xor::default();
+        let neq_bool: Neq<bool> = Neq::default();
         let nand: Nand = Nand::default();
         let nor: Nor = Nor::default();
         let exc: Exc = Exc::default();
@@ -347,6 +532,7 @@ mod tests {
         test_bool_bool(or);
         test_bool_bool(eq_bool);
         test_bool_bool(xor);
+        test_bool_bool(neq_bool);
         test_bool_bool(nand);
         test_bool_bool(nor);
         test_bool_bool(exc);
@@ -355,13 +541,30 @@ mod tests {
         test_bool_bool(nexc);
 
         test_bool_bool_path!(not: not, id_bool: id_bool,
-            [and, Or], [or, And], [eq_bool, Xor], [xor, Eq<bool>],
+            [and, Or], [or, And], [eq_bool, Xor], [xor, Eq<bool>], [neq_bool, Eq<bool>],
             [exc, Nrexc], [nrexc, Exc], [nexc, Rexc], [rexc, Nexc]);
 
+        let _: Nrexc<((), ())> = and.path_args(not, id_bool, not);
+        let _: Nexc<((), Id<bool>)> = and.i(((), not)).path_args(id_bool, not, not);
+        let _: Exc<(Id<bool>, Not)> = or.i((not, not)).path_args(not, id_bool, not);
+        let _: Rexc<(Id<bool>, Id<bool>)> = or.i((id_bool, not)).path_args(id_bool, not, not);
+        // `not(and(a, b)) = nrexc(not(a), b)`
+        for &(a, b) in &[(false, false), (false, true), (true, false), (true, true)] {
+            assert_eq!(not.eval(and.eval((a, b)).unwrap()), nrexc.eval((not.eval(a).unwrap(), b)));
+        }
+
+        let _: Not = not.path(not);
+        let _: Not<Id<bool>> = not.i(not).path(not);
+        let _: Id<bool> = id_bool.path(not);
+        let _: Id<bool, Id<bool>> = id_bool.i(not).path(not);
+        let _: () = false_1_bool.path(not);
+
         test_nat!(u8);
         test_nat!(u16);
         test_nat!(u32);
         test_nat!(u64);
+        test_nat!(u128);
+        test_nat!(usize);
 
         type Nat = u16;
         let add: Add<Nat> = Add::default();
@@ -374,6 +577,161 @@ mod tests {
         let _: Eq<bool, _> = add.path(even);
         let _: Xor = add.path(odd);
 
+        let mul: Mul<Nat> = Mul::default();
+        let _: Or = mul.path(even);
+        let _: And = mul.path(odd);
+        let _: Or<(Id<bool>, Not)> = mul.i((even, odd)).path(even);
+        let _: Or<(Not, Id<bool>)> = mul.i((odd, even)).path(even);
+        let _: Or<(Not, Not)> = mul.i((odd, odd)).path(even);
+
+        let sub: Sub<Nat> = Sub::default();
+        let _: Eq<bool> = sub.path(even);
+        let _: Xor = sub.path(odd);
+        let _: Eq<bool, (Id<bool>, Not)> = sub.i((even, odd)).path(even);
+        let _: Eq<bool, (Not, Id<bool>)> = sub.i((odd, even)).path(even);
+        let _: Eq<bool, (Not, Not)> = sub.i((odd, odd)).path(even);
+        let _: Xor<(Not, Not)> = sub.i((even, even)).path(odd);
+
+        let wrapping_add: WrappingAdd<Nat> = WrappingAdd::default();
+        let checked_add: CheckedAdd<Nat> = CheckedAdd::default();
+        let _: Eq<bool> = wrapping_add.path(even);
+        let _: Xor<(Id<bool>, Id<bool>)> = wrapping_add.i((odd, odd)).path(odd);
+        let _: Eq<bool> = checked_add.path(even);
+        let _: Xor = checked_add.path(odd);
+        let _: Eq<bool, (Id<bool>, Not)> = checked_add.i((even, odd)).path(even);
+
+        let id_bool: Id<bool> = Id::default();
+        let _: Id<bool> = even.path_to(even, id_bool);
+        let _: Not<Not> = even.i(even).path_to(odd, id_bool);
+        let _: Not<Id<bool>> = odd.i(even).path_to(even, id_bool);
+        let _: Eq<bool> = add.path_to(odd, even);
+        let _: Eq<bool, (Not, Id<bool>)> = add.i((even, odd)).path_to(odd, even);
+        let _: Eq<bool, (Id<bool>, Id<bool>)> = add.i((odd, odd)).path_to(odd, even);
+        let _: Xor<(Id<bool>, Not)> = add.i((even, odd)).path_to(even, odd);
+        let _: Nor = mul.path_to(even, odd);
+        let _: Nand<(Not, Id<bool>)> = mul.i((even, odd)).path_to(odd, even);
+        let _: Eq<bool, ((), ())> = add.path_args(even, odd, odd);
+        let _: Xor<(Id<bool>, Not)> = add.i((even, even)).path_args(even, odd, even);
+        let _: Nrexc<(Id<bool>, Id<bool>)> = mul.i((even, odd)).path_args(even, odd, even);
+        let _: Nexc<((), ())> = mul.path_args(odd, even, even);
+        // `even(add(a, b)) = eq(odd(a), odd(b))`
+        let eq_bool: Eq<bool> = add.path_to(odd, even);
+        for a in 0..4 {
+            for b in 0..4 {
+                assert_eq!(even.eval(add.eval((a, b)).unwrap()), eq_bool.eval((odd.eval(a).unwrap(), odd.eval(b).unwrap())));
+            }
+        }
+
+        let rem_3: RemK<Nat> = RemK {k: 3, i: ()};
+        // Checks `∃add_mod(3){∃rem(3)} <=> ∃rem(3){∃add}`, where `∃add => true_1`.
+        let add_mod_3: AddMod<Nat, LtK<Nat>> = add.path(rem_3);
+        assert_eq!(add_mod_3, AddMod {k: 3, i: LtK {k: 3, i: ()}});
+
+        let _: Id<bool> = Id::<Nat>::default().path(even);
+        let _: Id<bool> = Id::<Nat>::default().path(odd);
+        // `add(k)[even] <=> if even(k) {id} else {not}`
+        for k in 0..4 {
+            let add_k: AddK<Nat> = AddK {k, i: ()};
+            let path: IfK<Nat, Even<Nat>, Id<bool>, Not> = add_k.path(even);
+            let path_odd: IfK<Nat, Even<Nat>, Id<bool>, Not> = add_k.path(odd);
+            // `∃if_k(k, even, id, not){not} <=> if_k(k, even, not, id)`
+            let ex: IfK<Nat, Even<Nat>, Not, Id<bool>> = path.i(Not::default()).ex_path();
+            assert_eq!(ex.eval(true), Some(k % 2 == 1));
+            for x in 0..8 {
+                assert_eq!(even.eval(add_k.eval(x).unwrap()), path.eval(even.eval(x).unwrap()));
+                assert_eq!(odd.eval(add_k.eval(x).unwrap()), path_odd.eval(odd.eval(x).unwrap()));
+            }
+        }
+        let add_max: AddK<Nat> = AddK {k: Nat::MAX, i: ()};
+        let _: IfK<Nat, Even<Nat>, Id<bool>, Not> = add_max.path(even);
+        let _: IfK<Nat, Even<Nat>, Id<bool>, Not> = add_max.path(odd);
+
+        // `∃(even . add(2)) <=> ∃even{(>= 2)}`
+        let add_2: AddK<Nat> = AddK {k: 2, i: ()};
+        let even_add_2 = Compose::new(even, add_2);
+        assert_eq!(even_add_2.ex_path(), even.i(add_2.ex_path()).ex_path());
+        assert_eq!(even_add_2.eval(3), Some(false));
+        assert_eq!(even_add_2.eval(Nat::MAX), None);
+        let _: Compose<Even<Nat>, AddK<Nat, Odd<Nat>>> = even_add_2.i_force(odd);
+
+        {
+            type Int = i32;
+            let add: Add<Int> = Add::default();
+            let mul: Mul<Int> = Mul::default();
+            let neg: Neg<Int> = Neg::default();
+            let abs: Abs<Int> = Abs::default();
+            let even: Even<Int> = Even::default();
+            let odd: Odd<Int> = Odd::default();
+            let is_neg: IsNeg<Int> = IsNeg::default();
+            let _: Eq<bool> = add.path(even);
+            let _: Xor = add.path(odd);
+            let _: Or = mul.path(even);
+            let _: And = mul.path(odd);
+            let _: Id<bool> = neg.path(even);
+            let _: Id<bool> = neg.path(odd);
+            let _: Id<bool> = abs.path(even);
+            let _: Id<bool> = abs.path(odd);
+            let _: Compose<Id<bool>, Id<bool>> = Compose::new(neg, abs).path(even);
+            let _: Compose<Id<bool>, Xor> = Compose::new(neg, add).path(odd);
+            let non_zero: NonZero<Int> = NonZero::default();
+            let _: Xor<((), ())> = mul.i((non_zero, non_zero)).path(is_neg);
+            assert_eq!(mul.i((non_zero, non_zero)).eval((0, 3)), None);
+            // `∃add{(odd, odd)} => even`, e.g. `min = -1 + (min + 1)`.
+            assert_eq!(add.i((odd, odd)).ex_path(), even);
+        }
+
+        {
+            type Float = f64;
+            let add: Add<Float> = Add::default();
+            let mul: Mul<Float> = Mul::default();
+            let neg: Neg<Float> = Neg::default();
+            let abs: Abs<Float> = Abs::default();
+            let is_nan: IsNan<Float> = IsNan::default();
+            let is_finite: IsFinite<Float> = IsFinite::default();
+            let sign_bit: SignBit<Float> = SignBit::default();
+            let _: Not = neg.path(sign_bit);
+            let _: Id<bool> = neg.path(is_nan);
+            let _: Id<bool> = neg.path(is_finite);
+            let _: False1<bool> = abs.path(sign_bit);
+            let _: Id<bool> = abs.path(is_nan);
+            let _: Id<bool> = abs.path(is_finite);
+            let _: Xor<((), ())> = mul.i((is_finite, is_finite)).path(sign_bit);
+            // `∃add{(is_finite, is_finite)} => if(is_nan, false_1, true_1)`
+            let not_nan = add.i((is_finite, is_finite)).ex_path();
+            assert!(not_nan.check(&Float::INFINITY));
+            assert!(!not_nan.check(&Float::NAN));
+            assert_eq!(add.i((is_finite, is_finite)).eval((Float::MAX, Float::MAX)), Some(Float::INFINITY));
+            assert_eq!(mul.eval((0.0, Float::INFINITY)).map(|x| x.is_nan()), Some(true));
+            assert_eq!(abs.ex_path().eval(-0.0), Some(false));
+            assert_eq!(sign_bit.eval(-Float::NAN), Some(true));
+            assert_eq!(sign_bit.eval(neg.eval(Float::NAN).unwrap()), Some(!sign_bit.eval(Float::NAN).unwrap()));
+        }
+
+        {
+            // `∃add(3) => (>= 3)` is checked by the compiler.
+            let add_3: AddC<3> = AddC::default();
+            let ge_3: GeC<3> = add_3.ex_path();
+            let _: () = EqC::<3>::default().ex_path();
+            assert_eq!(ge_3.dynamic(), AddK {k: 3u64, i: ()}.ex_path());
+            assert_eq!(ge_3.ex_path(), GeK {k: 3u64, i: ()}.ex_path());
+            assert_eq!(add_3.eval(2), Some(5));
+            assert_eq!(ge_3.eval(2), Some(false));
+            // `∃add(3){(= 4)} => (= 7)`
+            assert_eq!(add_3.i(EqC::<4>::default()).ex_path().eval(7), Some(true));
+            assert_eq!(add_3.i(LtC::<4>::default()).ex_path().tr, RangeK {lo: 3, hi: 7, i: ()});
+            assert_eq!(format!("{}", add_3.i(Even::<u64>::default())), "add(3){even}");
+            // `add(3)[even] <=> not`
+            let even: Even<u64> = Even::default();
+            let odd: Odd<u64> = Odd::default();
+            let path: IfK<u64, Even<u64>, Id<bool>, Not> = add_3.path(even);
+            assert_eq!(path.eval(true), Some(false));
+            let _: IfK<u64, Even<u64>, Id<bool>, Not> = add_3.path(odd);
+            let _: IfK<u64, Even<u64>, Id<bool>, Not> = AddC::<4>::default().path(even);
+            assert_eq!(even.i(ge_3).ex_path(), even.i(GeK {k: 3u64, i: ()}).ex_path());
+            assert_eq!(odd.i(EqC::<4>::default()).ex_path(), odd.i(EqK {k: 4u64, i: ()}).ex_path());
+            assert_eq!(even.i(LtC::<1>::default()).ex_path(), even.i(LtK {k: 1u64, i: ()}).ex_path());
+        }
+
         assert_eq!(ge_2.k, 2);
 
         // ∃(>= k) => \(x: bool) = if k == 0 {x} else {true}
@@ -405,4 +763,316 @@ mod tests {
         let _: Eq<bool, (Not, Id<bool>)> = add.i((odd, even)).path(even);
         let _: Eq<bool, (Not, Not)> = add.i((odd, odd)).path(even);
     }
+
+    #[test]
+    fn eval() {
+        let not: Not = Not::default();
+        let id_bool: Id<bool> = Id::default();
+        let and: And = And::default();
+        assert_eq!(not.eval(true), Some(false));
+        assert_eq!(not.i(not).eval(true), None);
+        assert_eq!(not.i(not).eval(false), Some(true));
+        assert_eq!(and.eval((true, true)), Some(true));
+        assert_eq!(and.i((id_bool, ())).eval((false, true)), None);
+        assert_eq!(and.i(not).eval((false, true)), None);
+        assert_eq!(and.i(not).eval((false, false)), Some(false));
+
+        type Nat = u8;
+        let add: Add<Nat> = Add::default();
+        let even: Even<Nat> = Even::default();
+        let odd: Odd<Nat> = Odd::default();
+        let add_2: AddK<Nat> = AddK {k: 2, i: ()};
+        let lt_2: LtK<Nat> = LtK {k: 2, i: ()};
+        assert_eq!(add.eval((2, 3)), Some(5));
+        assert_eq!(add.eval((255, 1)), None);
+        let mul: Mul<Nat> = Mul::default();
+        let mul_3: MulK<Nat> = MulK {k: 3, i: ()};
+        assert_eq!(mul.eval((3, 5)), Some(15));
+        assert_eq!(mul.eval((16, 16)), None);
+        assert_eq!(mul_3.eval(4), Some(12));
+        assert_eq!(mul_3.i(EqK {k: 4, i: ()}).eval(5), None);
+        // `∃mul(3){(= 100)} => false_1`, since `3 * 100` overflows.
+        assert!(!mul_3.i(EqK {k: 100, i: ()}).ex_path().check(&44));
+        assert!(mul_3.i(EqK {k: 4, i: ()}).ex_path().check(&12));
+        assert!(mul_3.i(odd).ex_path().check(&9));
+        assert!(!mul_3.i(odd).ex_path().check(&6));
+        let sub: Sub<Nat> = Sub::default();
+        let sub_3: SubK<Nat> = SubK {k: 3, i: ()};
+        assert_eq!(sub.eval((5, 3)), Some(2));
+        assert_eq!(sub.eval((3, 5)), None);
+        assert_eq!(sub.tri_path().eval((3, 5)), Some(false));
+        assert_eq!(sub_3.eval(2), None);
+        assert_eq!(sub_3.tri_path().eval(2), Some(false));
+        assert_eq!(sub_3.i(odd).tri_path().eval(4), None);
+        let div: Div<Nat> = Div::default();
+        let rem_3: RemK<Nat> = RemK {k: 3, i: ()};
+        assert_eq!(div.eval((7, 2)), Some(3));
+        assert_eq!(div.eval((7, 0)), None);
+        assert!(!div.tri_path().check(&(7, 0)));
+        assert!(!div.i_force(even).tri_path().check(&(4, 0)));
+        assert!(!div.i_force(even).tri_path().check(&(4, 3)));
+        assert!(div.i_force(even).tri_path().check(&(4, 2)));
+        // The combinators also constrain the divisor.
+        let rem: Rem<Nat> = Rem::default();
+        let even_ge_2 = Both::new(even, GeK {k: 2, i: ()});
+        assert!(!rem.i_force(even_ge_2).tri_path().check(&(4, 0)));
+        assert!(rem.i_force(even_ge_2).tri_path().check(&(4, 2)));
+        assert!(!div.i_force(Either::new(odd, EqK {k: 0, i: ()})).tri_path().check(&(3, 0)));
+        assert!(!div.i_force(Complement::new(odd)).tri_path().check(&(4, 0)));
+        // `∀div(0) => false_1`
+        let div_0: DivK<Nat> = DivK {k: 0, i: ()};
+        assert!(!div_0.tri_path().check(&7));
+        assert!(DivK {k: 2, i: ()}.tri_path().check(&7u8));
+        assert!(!RemK {k: 0, i: even}.tri_path().check(&4));
+        assert_eq!(rem_3.eval(7), Some(1));
+        // `∃rem(3) => (< 3)`
+        assert_eq!(rem_3.ex_path(), LtK {k: 3, i: ()});
+        assert_eq!(add.i((even, odd)).eval((2, 3)), Some(5));
+        assert_eq!(add.i((even, odd)).eval((3, 3)), None);
+        assert_eq!(add_2.eval(3), Some(5));
+        assert_eq!(add_2.i(even).eval(3), None);
+        let neq_2: NeqK<Nat> = NeqK {k: 2, i: ()};
+        assert_eq!(neq_2.eval(2), Some(false));
+        assert_eq!(neq_2.i(odd).eval(3), Some(true));
+        let range_2_5: RangeK<Nat> = RangeK {lo: 2, hi: 5, i: ()};
+        assert_eq!(range_2_5.eval(1), Some(false));
+        assert_eq!(range_2_5.eval(2), Some(true));
+        assert_eq!(range_2_5.eval(5), Some(false));
+        // `∃add(2){(< 2)} => [2, 4)`
+        assert_eq!(add_2.i(lt_2).ex_path().tr, RangeK {lo: 2, hi: 4, i: ()});
+        // `∃add(2){[2, 5)} => [4, 7)`
+        assert_eq!(add_2.i(range_2_5).ex_path().tr, RangeK {lo: 4, hi: 7, i: ()});
+        let neg: Neg<i8> = Neg::default();
+        let abs: Abs<i8> = Abs::default();
+        assert_eq!(neg.eval(-3), Some(3));
+        assert_eq!(neg.eval(i8::MIN), None);
+        assert_eq!(neg.tri_path(), NeqK {k: i8::MIN, i: ()});
+        assert_eq!(abs.eval(-128), None);
+        assert_eq!(abs.i(Odd::default()).eval(-3), Some(3));
+        assert_eq!(IsNeg::<i8>::default().eval(-1), Some(true));
+        assert_eq!(IsPos::<i8>::default().eval(0), Some(false));
+        assert_eq!(Odd::<i8>::default().eval(-3), Some(true));
+        let mod_3_1: ModK<Nat> = ModK {m: 3, r: 1, i: ()};
+        assert_eq!(mod_3_1.eval(7), Some(true));
+        assert_eq!(mod_3_1.eval(8), Some(false));
+        assert_eq!(ModK {m: 0u8, r: 7, i: ()}.eval(7), Some(true));
+        let add_mod_3: AddMod<Nat> = AddMod {k: 3, i: ()};
+        assert_eq!(add_mod_3.eval((200, 200)), Some(1));
+        assert_eq!(AddMod {k: 0u8, i: ()}.eval((1, 2)), None);
+        // `∃mul(3) => (% 3 = 0)`
+        assert_eq!(mul_3.ex_path(), ModK {m: 3, r: 0, i: ()});
+        // `∃mul{(even, even)} => (% 4 = 0)`
+        assert_eq!(mul.i((even, even)).ex_path(), ModK {m: 4, r: 0, i: ()});
+        assert_eq!(mul.i(even).ex_path(), ModK {m: 4, r: 0, i: ()});
+        // `∃mul{(= 15)} => (= 225)`, while `16 * 16` overflows.
+        assert_eq!(mul.i(EqK {k: 15, i: ()}).ex_path().eval(225), Some(true));
+        assert_eq!(mul.i(EqK {k: 16, i: ()}).ex_path().eval(0), Some(false));
+        let add_u128: Add<u128> = Add::default();
+        assert_eq!(add_u128.eval((u128::MAX, 1)), None);
+        assert_eq!(Odd::<usize>::default().eval(7), Some(true));
+
+        let big = BigNat::from(u64::MAX) + BigNat::from(1u8);
+        assert_eq!(format!("{}", big), "18446744073709551616");
+        assert!(big.is_even() && big > BigNat::from(u64::MAX));
+        let add_big: AddK<BigNat> = AddK {k: big.clone(), i: ()};
+        assert_eq!(add_big.eval(BigNat::from(u64::MAX)), Some(&big + &BigNat::from(u64::MAX)));
+        // `∃(>= max){even}` is `not` for `u8`, but `true_1` for `BigNat`.
+        assert_eq!(GeK {k: 255u8, i: Even::default()}.ex_path().eval(true), Some(false));
+        assert_eq!(GeK {k: BigNat::from(255u8), i: Even::default()}.ex_path().eval(true), Some(true));
+
+        let wrapping_add: WrappingAdd<Nat> = WrappingAdd::default();
+        let checked_add: CheckedAdd<Nat> = CheckedAdd::default();
+        let saturating_add: SaturatingAdd<Nat> = SaturatingAdd::default();
+        assert_eq!(wrapping_add.eval((255, 3)), Some(2));
+        assert_eq!(checked_add.eval((255, 3)), None);
+        assert_eq!(checked_add.tri_path().eval((255, 3)), Some(false));
+        assert_eq!(checked_add.tri_path().eval((252, 3)), Some(true));
+        assert_eq!(saturating_add.eval((255, 3)), Some(255));
+        // `∃add(200){(= 100)} => false_1`, without overflowing at higher order.
+        let add_200: AddK<Nat> = AddK {k: 200, i: ()};
+        assert_eq!(add_200.i(EqK {k: 100, i: ()}).ex_path().eval(44), Some(false));
+        assert_eq!(add_200.i(GeK {k: 55, i: ()}).ex_path().eval(255), Some(true));
+        assert_eq!(add_200.i(GeK {k: 56, i: ()}).ex_path().eval(255), Some(false));
+
+        // `∃add(2) => (>= 2)`
+        let ge_2 = add_2.ex_path();
+        assert_eq!(ge_2.eval(1), Some(false));
+        assert_eq!(ge_2.eval(2), Some(true));
+        // `∃(>= 2) => if 2 == 0 {id} else {true_1}`
+        assert_eq!(ge_2.ex_path().eval(false), Some(true));
+
+        // `∃add{(odd, odd)} => if((< 2), false_1, even)`
+        let false_1: False1<Nat> = False1::default();
+        let if_lt_2_false_even = If {co: lt_2, tr: false_1, fa: even, i: ()};
+        assert_eq!(if_lt_2_false_even.eval(0), Some(false));
+        assert_eq!(if_lt_2_false_even.eval(4), Some(true));
+        assert_eq!(if_lt_2_false_even.eval(5), Some(false));
+
+        // `∃if(co, tr, fa) <=> ∃tr{co} ∨ ∃fa{¬co}`, compared with the simplified existential paths.
+        for k in [0, 1, 2, 3, 254, 255] {
+            let ge_k: GeK<Nat> = GeK {k, i: ()};
+            let lt_k: LtK<Nat> = LtK {k, i: ()};
+            let if_ge_k_even_odd = If {co: ge_k, tr: even, fa: odd, i: ()};
+            let if_lt_k_odd_even = If {co: lt_k, tr: odd, fa: even, i: ()};
+            let if_lt_k_false_even = If {co: lt_k, tr: false_1, fa: even, i: ()};
+            for x in [false, true] {
+                // `∃if((>= k), even, odd) <=> true_1`
+                assert!(Union::new(if_ge_k_even_odd).ex_path().check(&x));
+                assert!(Union::new(if_lt_k_odd_even).ex_path().check(&x));
+                assert_eq!(Union::new(if_lt_k_false_even).ex_path().check(&x), if_lt_k_false_even.ex_path().check(&x));
+            }
+            // `∃if((< k), false_1, even){odd} <=> ∃false_1{(< k) ∧ odd} ∨ ∃even{(>= k) ∧ odd}`
+            let if_lt_k_false_even_odd = If {co: lt_k, tr: false_1, fa: even, i: odd};
+            let union = Union::new(if_lt_k_false_even_odd).ex_path();
+            assert_eq!(Union::new(if_lt_k_false_even).i(odd).ex_path(), union);
+            assert_eq!(Union::new(if_lt_k_false_even_odd).eval(3), if_lt_k_false_even_odd.eval(3));
+            for y in [false, true] {
+                let in_image = (0..=255).any(|x| if_lt_k_false_even_odd.eval(x) == Some(y));
+                assert_eq!(union.check(&y), in_image);
+            }
+        }
+        assert_eq!(Both::new(even, GeK {k: 2, i: ()}).eval(2), Some(true));
+        assert_eq!(Both::new(even, GeK {k: 2, i: ()}).eval(0), Some(false));
+        assert_eq!(Either::new(odd, LtK {k: 2, i: ()}).eval(0), Some(true));
+        assert_eq!(Either::new(odd, LtK {k: 2, i: ()}).eval(2), Some(false));
+        assert_eq!(Complement::new(even).eval(3), Some(true));
+        assert_eq!(Complement::new(even).i_force(GeK {k: 4, i: ()}).eval(3), None);
+        // `¬(even ∧ (>= 2)) <=> odd ∨ (< 2)`
+        assert_eq!(Both::new(even, GeK {k: 2, i: ()}).negate(), Either::new(odd, LtK {k: 2, i: ()}));
+        // `∃even{(< 2) ∧ (>= 2)} => false_1`
+        assert_eq!(even.i(Both::new(LtK {k: 2, i: ()}, GeK {k: 2, i: ()})).ex_path().eval(false), Some(false));
+        assert_eq!(even.i(Both::new(LtK {k: 2, i: ()}, GeK {k: 2, i: ()})).ex_path().eval(true), Some(false));
+        // `∃even{¬(>= 1)} => id`
+        assert!(!even.i(Complement::new(GeK {k: 1, i: ()})).ex_path().check(&false));
+        // `∃add(3){even ∧ (>= 4)} => odd ∧ (>= 7)`
+        let add_3: AddK<u8> = AddK {k: 3, i: ()};
+        let lift = add_3.i(Both::new(even, GeK {k: 4, i: ()})).ex_path();
+        assert!(lift.check(&7));
+        assert!(!lift.check(&6));
+        assert!(!lift.check(&5));
+        // `∃add{odd ∧ (< 4)} => even ∧ (>= 2) ∧ (<= 6)`
+        let lift = add.i(Both::new(LtK {k: 4, i: ()}, odd)).ex_path();
+        assert_eq!((0..10).filter(|x| lift.check(x)).collect::<Vec<u8>>(), vec![2, 4, 6]);
+        // `∃(>= 5){even ∧ (= 4)} => not`
+        let lift = GeK {k: 5, i: ()}.i(Both::new(even, EqK {k: 4, i: ()})).ex_path();
+        assert!(lift.check(&false));
+        assert!(!lift.check(&true));
+    }
+
+    #[test]
+    fn display() {
+        let and: And = And::default();
+        let not: Not = Not::default();
+        assert_eq!(format!("{}", and), "and");
+        assert_eq!(format!("{}", and.i_force((not, ()))), "and{not, true_1}");
+        assert_eq!(render(&()), "true_1");
+        assert_eq!(render(&(not, ())), "not, true_1");
+
+        let even: Even<u8> = Even::default();
+        let lt_2: LtK<u8> = LtK {k: 2, i: ()};
+        let false_1: False1<u8> = False1::default();
+        let add: Add<u8> = Add::default();
+        assert_eq!(format!("{}", If {co: lt_2, tr: false_1, fa: even, i: ()}), "if((< 2), false_1, even)");
+        assert_eq!(format!("{}", add.i_force((lt_2, even))), "add{(< 2), even}");
+        let add_2: AddK<u8> = AddK {k: 2, i: ()};
+        assert_eq!(format!("{}", Compose::new(even, add_2.i(lt_2))), "even . add(2){(< 2)}");
+        assert_eq!(format!("{}", AddK {k: 2u8, i: ()}.i(lt_2).ex_path()),
+            "if (<= 253)(2) {[2, 4)} else {(>= 2)}");
+
+        let ifk = IfK {k: 3u8, co: EqK {k: 0u8, i: ()}, tr: (), fa: not, i: ()};
+        assert_eq!(format!("{}", ifk), "if (= 0)(3) {true_1} else {not}");
+        let ifk_even = IfK {k: 3u8, co: EqK {k: 0u8, i: ()}, tr: (), fa: not, i: even};
+        assert_eq!(format!("{}", ifk_even), "if (= 0)(3) {true_1{even}} else {not{even}}");
+
+        let ge_2: GeK<u8> = GeK {k: 2, i: ()};
+        assert_eq!(format!("{}", Both::new(even, ge_2)), "even ∧ (>= 2)");
+        assert_eq!(format!("{}", Either::new(even, lt_2)), "even ∨ (< 2)");
+        assert_eq!(format!("{}", Complement::new(EqK {k: 2u8, i: ()})), "¬(= 2)");
+        assert_eq!(format!("{}", Complement::new(even).i_force(ge_2)), "(¬even){(>= 2)}");
+        assert_eq!(format!("{}", add.i_force(Both::new(even, ge_2))), "add{even ∧ (>= 2)}");
+        // Nested `∧`, `∨` and `¬` are put in parentheses.
+        let odd: Odd<u8> = Odd::default();
+        assert_eq!(format!("{}", Complement::new(Both::new(even, odd))), "¬(even ∧ odd)");
+        assert_eq!(format!("{}", Both::new(even, Either::new(odd, even))), "even ∧ (odd ∨ even)");
+        assert_eq!(format!("{}", Either::new(Both::new(even, odd), even)), "(even ∧ odd) ∨ even");
+        assert_eq!(format!("{}", Both::new(Complement::new(even), odd)), "(¬even) ∧ odd");
+        assert_eq!(format!("{}", Complement::new(Complement::new(even))), "¬(¬even)");
+        assert_eq!(format!("{}", Both::new(Complement::new(even).i_force(ge_2), odd)), "(¬even){(>= 2)} ∧ odd");
+        assert_eq!(format!("{}", Union::new(If {co: lt_2, tr: false_1, fa: even, i: ()})),
+            "union(if((< 2), false_1, even))");
+    }
+
+    // A wrong theorem `add_mod(k)[rem(k2)] <=> rem(k2 - 1)`, for testing `try_path`.
+    impl Path<RemK<u8>> for AddMod<u8> {
+        type Lift = RemK<u8>;
+
+        fn path_force(&self, arg: RemK<u8>) -> RemK<u8> {
+            RemK {k: arg.k - 1, i: ()}
+        }
+    }
+
+    // The same wrong theorem `add_mod(k)[rem(k2) -> rem(k2)] <=> rem(k2 - 1)`, for testing `try_path_to`.
+    impl Path<(RemK<u8>, RemK<u8>)> for AddMod<u8> {
+        type Lift = RemK<u8>;
+
+        fn path_force(&self, arg: (RemK<u8>, RemK<u8>)) -> RemK<u8> {
+            RemK {k: arg.1.k - 1, i: ()}
+        }
+    }
+
+    #[test]
+    fn try_path() {
+        let add: Add<u8> = Add::default();
+        let even: Even<u8> = Even::default();
+        let eq: Eq<bool> = add.try_path(even).unwrap();
+        assert_eq!(eq, Eq::default());
+
+        let rem_3: RemK<u8> = RemK {k: 3, i: ()};
+        let add_mod_3: AddMod<u8, LtK<u8>> = add.try_path(rem_3).unwrap();
+        assert_eq!(add_mod_3, AddMod {k: 3, i: LtK {k: 3, i: ()}});
+
+        let add_mod_5: AddMod<u8> = AddMod {k: 5, i: ()};
+        let err = add_mod_5.try_path(rem_3).unwrap_err();
+        assert_eq!(err, PathError {
+            function: "add_mod(5)".into(),
+            property: "rem(3)".into(),
+            constraint: "true_1".into(),
+            left: "(< 2)".into(),
+            right: "(< 3)".into(),
+        });
+        assert_eq!(format!("{}", err),
+            "No path `add_mod(5)[rem(3)]` for `true_1`: `(< 2)` != `(< 3)`");
+
+        let odd: Odd<u8> = Odd::default();
+        let eq: Eq<bool> = add.try_path_to(odd, even).unwrap();
+        assert_eq!(eq, Eq::default());
+        let err = add_mod_5.try_path_to(rem_3, rem_3).unwrap_err();
+        assert_eq!(err.property, "rem(3) -> rem(3)");
+        assert_eq!(format!("{}", err),
+            "No path `add_mod(5)[rem(3) -> rem(3)]` for `true_1`: `(< 2)` != `(< 3)`");
+
+        let eq: Eq<bool, ((), ())> = add.try_path_args(even, odd, odd).unwrap();
+        assert_eq!(eq, Eq::default());
+        let _: Nrexc<(Id<bool>, Id<bool>)> = Mul::<u8>::default().i((even, odd)).try_path_args(even, odd, even).unwrap();
+    }
+
+    #[test]
+    fn verify_bool_dictionary() {
+        assert_eq!(verify_bool(), vec![]);
+    }
+
+    #[test]
+    fn verify_u8_nat() {
+        assert_eq!(verify_u8(), vec![]);
+    }
+
+    #[test]
+    fn verify_i8_int() {
+        assert_eq!(verify_i8(), vec![]);
+    }
+
+    #[test]
+    fn verify_big_nat_window() {
+        assert_eq!(verify_big_nat(), vec![]);
+    }
 }
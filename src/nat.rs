@@ -0,0 +1,59 @@
+//! Natural numbers.
+
+use std::fmt::Debug;
+use std::ops;
+
+/// Implemented by natural numbers, which are the unsigned integer types.
+///
+/// The dictionary of natural numbers is implemented once for all types with this trait.
+/// The methods have the same behavior as the inherent methods of the unsigned integer types.
+pub trait Nat: Copy + Ord + Default + Debug + From<u8> + 'static
+    + ops::Add<Output = Self> + ops::Sub<Output = Self> + ops::Mul<Output = Self>
+    + ops::Div<Output = Self> + ops::Rem<Output = Self>
+{
+    /// `0`
+    const ZERO: Self;
+    /// `1`
+    const ONE: Self;
+    /// The largest natural number of this type.
+    const MAX: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_sub(self, other: Self) -> Self;
+    fn saturating_mul(self, other: Self) -> Self;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_sub(self, other: Self) -> Self;
+}
+
+macro_rules! nat_impl {
+    ($t:ident) => {
+        impl Nat for $t {
+            const ZERO: $t = 0;
+            const ONE: $t = 1;
+            const MAX: $t = $t::MAX;
+
+            fn checked_add(self, other: $t) -> Option<$t> {$t::checked_add(self, other)}
+            fn checked_sub(self, other: $t) -> Option<$t> {$t::checked_sub(self, other)}
+            fn checked_mul(self, other: $t) -> Option<$t> {$t::checked_mul(self, other)}
+            fn checked_div(self, other: $t) -> Option<$t> {$t::checked_div(self, other)}
+            fn checked_rem(self, other: $t) -> Option<$t> {$t::checked_rem(self, other)}
+            fn saturating_add(self, other: $t) -> $t {$t::saturating_add(self, other)}
+            fn saturating_sub(self, other: $t) -> $t {$t::saturating_sub(self, other)}
+            fn saturating_mul(self, other: $t) -> $t {$t::saturating_mul(self, other)}
+            fn wrapping_add(self, other: $t) -> $t {$t::wrapping_add(self, other)}
+            fn wrapping_sub(self, other: $t) -> $t {$t::wrapping_sub(self, other)}
+        }
+    };
+}
+
+nat_impl!{u8}
+nat_impl!{u16}
+nat_impl!{u32}
+nat_impl!{u64}
+nat_impl!{u128}
+nat_impl!{usize}
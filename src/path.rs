@@ -1,5 +1,72 @@
 use *;
 
+use std::fmt;
+
+/// Reported by `try_path` when captured variables of the existential paths differ.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathError {
+    /// `f`.
+    pub function: String,
+    /// `g`, or `g_in -> g_out` for `try_path_to` and `(g0, g1) -> g2` for `try_path_args`.
+    pub property: String,
+    /// `∀f`.
+    pub constraint: String,
+    /// `∃f[g]{∃g{∀f}}`.
+    pub left: String,
+    /// `∃g{∃f{∀f}}`.
+    pub right: String,
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        write!(w, "No path `{}[{}]` for `{}`: `{}` != `{}`",
+            self.function, self.property, self.constraint, self.left, self.right)
+    }
+}
+
+impl std::error::Error for PathError {}
+
+/// Both sides of a path `f[g_in -> g_out] <=> h`, which are compared by the `Path` traits.
+///
+/// The path exists when `∃h{∃g_in{∀f}}` and `∃g_out{∃f{∀f}}` have the same type
+/// and the same captured variables.
+/// Implemented for all functions where both sides have the same type.
+pub trait PathCheck<GIn, GOut, H>: TriPath + ExPath {
+    /// `∃g_out{∃f{∀f}}`.
+    type Side;
+
+    /// Returns `(∃h{∃g_in{∀f}}, ∃g_out{∃f{∀f}})`.
+    fn path_check(&self, g_in: GIn, g_out: GOut, h: &H) -> (Self::Side, Self::Side);
+}
+
+impl<F, GIn, GOut, H> PathCheck<GIn, GOut, H> for F
+    where
+        // `∀f`
+        F: TriPath,
+        // `∃f{∀f}`
+        F: ExPath,
+        // `g_in{∀f}`
+        GIn: Constrain<<F as TriPath>::Lift>,
+        // `∃g_in{∀f}`
+        <GIn as Constrain<<F as TriPath>::Lift>>::Lift: ExPath,
+        // `g_out{∃f{∀f}}`
+        GOut: Constrain<<F as ExPath>::Lift>,
+        // `∃g_out{∃f{∀f}}`
+        <GOut as Constrain<<F as ExPath>::Lift>>::Lift: ExPath,
+        // `h{∃g_in{∀f}}`,
+        H: Constrain<<<GIn as Constrain<<F as TriPath>::Lift>>::Lift as ExPath>::Lift>,
+        // `∃h{∃g_in{∀f}} <=> ∃g_out{∃f{∀f}}`
+        <H as Constrain<<<GIn as Constrain<<F as TriPath>::Lift>>::Lift as ExPath>::Lift>>::Lift: ExPath<
+            Lift = <<GOut as Constrain<<F as ExPath>::Lift>>::Lift as ExPath>::Lift
+        >,
+{
+    type Side = <<GOut as Constrain<<F as ExPath>::Lift>>::Lift as ExPath>::Lift;
+
+    fn path_check(&self, g_in: GIn, g_out: GOut, h: &H) -> (Self::Side, Self::Side) {
+        (h.i(g_in.i(self.tri_path()).ex_path()).ex_path(), g_out.i(self.ex_path()).ex_path())
+    }
+}
+
 pub trait Path<T> {
     type Lift;
 
@@ -9,46 +76,168 @@ pub trait Path<T> {
     /// Performs a runtime check that captured variables are equal.
     fn path(&self, arg: T) -> <Self as Path<T>>::Lift
         where
-            // `∀f`
-            Self: TriPath,
-            // `∃f{∀f}`
-            Self: ExPath,
-            // `g{∀f}`
-            T: Constrain<<Self as TriPath>::Lift>,
-            // `∃g{∀f}`
-            <T as Constrain<<Self as TriPath>::Lift>>::Lift: ExPath,
-            // `g{∃f{∀f}}`
-            T: Constrain<<Self as ExPath>::Lift>,
-            // `∃g{∃f{∀f}}`
-            <T as Constrain<<Self as ExPath>::Lift>>::Lift: ExPath,
-            // `f[g]{∃g{∀f}}`,
-            <Self as Path<T>>::Lift: Constrain<<<T as Constrain<<Self as TriPath>::Lift>>::Lift as ExPath>::Lift>,
             // `∃f[g]{∃g{∀f}} <=> ∃g{∃f{∀f}}`
-            <<Self as Path<T>>::Lift as Constrain<<<T as Constrain<<Self as TriPath>::Lift>>::Lift as ExPath>::Lift>
-            >::Lift: ExPath<
-                Lift = <<T as Constrain<<Self as ExPath>::Lift>>::Lift as ExPath>::Lift
-            >,
+            Self: PathCheck<T, T, <Self as Path<T>>::Lift>,
             // Required for runtime check of captured variables.
             T: Clone,
-            <<T as Constrain<<Self as ExPath>::Lift>>::Lift as ExPath>::Lift: std::fmt::Debug + PartialEq,
+            <Self as PathCheck<T, T, <Self as Path<T>>::Lift>>::Side: std::fmt::Debug + PartialEq,
     {
-        use std::mem::transmute;
+        let fg: <Self as Path<T>>::Lift = self.path_force(arg.clone());
+        let (a, b) = self.path_check(arg.clone(), arg, &fg);
+        assert_eq!(a, b);
+        fg
+    }
 
-        // Check equality of captured variables.
-        // Unsafe code is needed because the compiler is not able infer
-        // that `a` and `b` are of the same type.
+    /// Same as `path`, but returns an error instead of panicking
+    /// when captured variables are not equal.
+    fn try_path(&self, arg: T) -> Result<<Self as Path<T>>::Lift, PathError>
+        where
+            // `∃f[g]{∃g{∀f}} <=> ∃g{∃f{∀f}}`
+            Self: PathCheck<T, T, <Self as Path<T>>::Lift>,
+            // Required for runtime check of captured variables.
+            T: Clone,
+            <Self as PathCheck<T, T, <Self as Path<T>>::Lift>>::Side: PartialEq,
+            // Required for reporting errors.
+            Self: Render,
+            T: Render,
+            <Self as TriPath>::Lift: Render,
+            <Self as PathCheck<T, T, <Self as Path<T>>::Lift>>::Side: Render,
+    {
         let fg: <Self as Path<T>>::Lift = self.path_force(arg.clone());
-        let a = unsafe {
-            transmute::<&_, &<<T as Constrain<<Self as ExPath>::Lift>>::Lift as ExPath>::Lift>(
-                &fg.i(arg.i(self.tri_path()).ex_path())
-            )
-        };
-        let b = &arg.i(self.ex_path()).ex_path();
+        let (a, b) = self.path_check(arg.clone(), arg.clone(), &fg);
+        if a == b {
+            Ok(fg)
+        } else {
+            Err(PathError {
+                function: render(self),
+                property: render(&arg),
+                constraint: render(&self.tri_path()),
+                left: render(&a),
+                right: render(&b),
+            })
+        }
+    }
+}
+
+/// Asymmetric path `f[g_in -> g_out] <=> h`, e.g. `add[odd -> even] <=> eq`.
+///
+/// The property `g_in` is applied to inputs and `g_out` to output,
+/// such that `g_out(f(a, b)) = h(g_in(a), g_in(b))`.
+/// Implemented for all functions with a `Path<(GIn, GOut)>`.
+pub trait PathTo<GIn, GOut>: Path<(GIn, GOut)> {
+    /// Can call method if the existential paths of constrained input matches.
+    /// Performs a runtime check that captured variables are equal.
+    fn path_to(&self, g_in: GIn, g_out: GOut) -> <Self as Path<(GIn, GOut)>>::Lift
+        where
+            // `∃f[g_in -> g_out]{∃g_in{∀f}} <=> ∃g_out{∃f{∀f}}`
+            Self: PathCheck<GIn, GOut, <Self as Path<(GIn, GOut)>>::Lift>,
+            // Required for runtime check of captured variables.
+            GIn: Clone,
+            GOut: Clone,
+            <Self as PathCheck<GIn, GOut, <Self as Path<(GIn, GOut)>>::Lift>>::Side: std::fmt::Debug + PartialEq,
+    {
+        let fg: <Self as Path<(GIn, GOut)>>::Lift = self.path_force((g_in.clone(), g_out.clone()));
+        let (a, b) = self.path_check(g_in, g_out, &fg);
         assert_eq!(a, b);
         fg
     }
+
+    /// Same as `path_to`, but returns an error instead of panicking
+    /// when captured variables are not equal.
+    fn try_path_to(&self, g_in: GIn, g_out: GOut) -> Result<<Self as Path<(GIn, GOut)>>::Lift, PathError>
+        where
+            // `∃f[g_in -> g_out]{∃g_in{∀f}} <=> ∃g_out{∃f{∀f}}`
+            Self: PathCheck<GIn, GOut, <Self as Path<(GIn, GOut)>>::Lift>,
+            // Required for runtime check of captured variables.
+            GIn: Clone,
+            GOut: Clone,
+            <Self as PathCheck<GIn, GOut, <Self as Path<(GIn, GOut)>>::Lift>>::Side: PartialEq,
+            // Required for reporting errors.
+            Self: Render,
+            GIn: Render,
+            GOut: Render,
+            <Self as TriPath>::Lift: Render,
+            <Self as PathCheck<GIn, GOut, <Self as Path<(GIn, GOut)>>::Lift>>::Side: Render,
+    {
+        let fg: <Self as Path<(GIn, GOut)>>::Lift = self.path_force((g_in.clone(), g_out.clone()));
+        let (a, b) = self.path_check(g_in.clone(), g_out.clone(), &fg);
+        if a == b {
+            Ok(fg)
+        } else {
+            Err(PathError {
+                function: render(self),
+                property: format!("{} -> {}", render(&g_in), render(&g_out)),
+                constraint: render(&self.tri_path()),
+                left: render(&a),
+                right: render(&b),
+            })
+        }
+    }
 }
 
+impl<F: Path<(GIn, GOut)>, GIn, GOut> PathTo<GIn, GOut> for F {}
+
+/// Binary path with a property per argument `f[(g0, g1) -> g2] <=> h`,
+/// e.g. `and[(not, id) -> not] <=> nrexc`.
+///
+/// This generalizes `PathTo`, such that `g2(f(a, b)) = h(g0(a), g1(b))`.
+/// Implemented for all functions with a `Path<(G0, G1, G2)>`.
+pub trait PathArgs<G0, G1, G2>: Path<(G0, G1, G2)> {
+    /// Can call method if the existential paths of constrained input matches.
+    /// Performs a runtime check that captured variables are equal.
+    fn path_args(&self, g0: G0, g1: G1, g2: G2) -> <Self as Path<(G0, G1, G2)>>::Lift
+        where
+            // `∃f[(g0, g1) -> g2]{(∃g0{∀f.0}, ∃g1{∀f.1})} <=> ∃g2{∃f{∀f}}`
+            Self: PathCheck<(G0, G1), G2, <Self as Path<(G0, G1, G2)>>::Lift>,
+            // Required for runtime check of captured variables.
+            G0: Clone,
+            G1: Clone,
+            G2: Clone,
+            <Self as PathCheck<(G0, G1), G2, <Self as Path<(G0, G1, G2)>>::Lift>>::Side: std::fmt::Debug + PartialEq,
+    {
+        let fg: <Self as Path<(G0, G1, G2)>>::Lift = self.path_force((g0.clone(), g1.clone(), g2.clone()));
+        let (a, b) = self.path_check((g0, g1), g2, &fg);
+        assert_eq!(a, b);
+        fg
+    }
+
+    /// Same as `path_args`, but returns an error instead of panicking
+    /// when captured variables are not equal.
+    fn try_path_args(&self, g0: G0, g1: G1, g2: G2) -> Result<<Self as Path<(G0, G1, G2)>>::Lift, PathError>
+        where
+            // `∃f[(g0, g1) -> g2]{(∃g0{∀f.0}, ∃g1{∀f.1})} <=> ∃g2{∃f{∀f}}`
+            Self: PathCheck<(G0, G1), G2, <Self as Path<(G0, G1, G2)>>::Lift>,
+            // Required for runtime check of captured variables.
+            G0: Clone,
+            G1: Clone,
+            G2: Clone,
+            <Self as PathCheck<(G0, G1), G2, <Self as Path<(G0, G1, G2)>>::Lift>>::Side: PartialEq,
+            // Required for reporting errors.
+            Self: Render,
+            G0: Render,
+            G1: Render,
+            G2: Render,
+            <Self as TriPath>::Lift: Render,
+            <Self as PathCheck<(G0, G1), G2, <Self as Path<(G0, G1, G2)>>::Lift>>::Side: Render,
+    {
+        let fg: <Self as Path<(G0, G1, G2)>>::Lift = self.path_force((g0.clone(), g1.clone(), g2.clone()));
+        let (a, b) = self.path_check((g0.clone(), g1.clone()), g2.clone(), &fg);
+        if a == b {
+            Ok(fg)
+        } else {
+            Err(PathError {
+                function: render(self),
+                property: format!("({}, {}) -> {}", render(&g0), render(&g1), render(&g2)),
+                constraint: render(&self.tri_path()),
+                left: render(&a),
+                right: render(&b),
+            })
+        }
+    }
+}
+
+impl<F: Path<(G0, G1, G2)>, G0, G1, G2> PathArgs<G0, G1, G2> for F {}
+
 macro_rules! path_impl {
     (sym $a:ident , $b:ident , $c:ident) => {
         impl<T: Clone> Path<$b> for $a<T>
@@ -83,6 +272,17 @@ macro_rules! path_impl {
             }
         }
     };
+    (sym $a:ident < $t:ident >, $b:ident , $c:ident < $t2:ident >) => {
+        impl<T: Clone> Path<$b> for $a<$t, T>
+            where $b<T>: ExPath
+        {
+            type Lift = $c<$t2, <<$b as Constrain<T>>::Lift as ExPath>::Lift>;
+
+            fn path_force(&self, arg: $b) -> Self::Lift {
+                $c {t: PhantomData, i: arg.i_force(self.i.clone()).ex_path()}
+            }
+        }
+    };
     (sym $a:ident < $t:ident >, $b:ident < $bt:ident >, $c:ident) => {
         impl<T: Clone> Path<$b<$bt>> for $a<$t, T>
             where $b<$bt, T>: ExPath
@@ -105,6 +305,28 @@ macro_rules! path_impl {
             }
         }
     };
+    (nat $a:ident , $b:ident , $c:ident) => {
+        impl<N: Nat, T: Clone> Path<$b<N>> for $a<N, T>
+            where $b<N, T>: ExPath
+        {
+            type Lift = $c<<<$b<N> as Constrain<T>>::Lift as ExPath>::Lift>;
+
+            fn path_force(&self, arg: $b<N>) -> Self::Lift {
+                $c {i: arg.i_force(self.i.clone()).ex_path()}
+            }
+        }
+    };
+    (nat $a:ident , $b:ident , $c:ident < $t2:ident >) => {
+        impl<N: Nat, T: Clone> Path<$b<N>> for $a<N, T>
+            where $b<N, T>: ExPath
+        {
+            type Lift = $c<$t2, <<$b<N> as Constrain<T>>::Lift as ExPath>::Lift>;
+
+            fn path_force(&self, arg: $b<N>) -> Self::Lift {
+                $c {t: PhantomData, i: arg.i_force(self.i.clone()).ex_path()}
+            }
+        }
+    };
     ($a:ident , $b:ident , $c:ident) => {
         impl Path<$b> for $a {
             type Lift = $c;
@@ -120,21 +342,243 @@ path_impl!{sym Or, Not, And}
 path_impl!{sym And, Not, Or}
 path_impl!{sym Eq<bool>, Not, Xor}
 path_impl!{sym Xor, Not, Eq<bool>}
+path_impl!{sym Neq<bool>, Not, Eq<bool>}
 path_impl!{sym Nor, Not, Nand}
 path_impl!{sym Nand, Not, Nor}
 path_impl!{sym Exc, Not, Nrexc}
 path_impl!{sym Nrexc, Not, Exc}
 path_impl!{sym Rexc, Not, Nexc}
 path_impl!{sym Nexc, Not, Rexc}
+path_impl!{sym Not, Not, Not}
 
-macro_rules! nat_impl {
+// `false_1[not] <=> true_1`
+impl<I> Path<Not> for False1<bool, I> {
+    type Lift = ();
+
+    fn path_force(&self, _: Not) -> Self::Lift {}
+}
+
+// `id[g] <=> id`
+impl<T, I: Clone, G> Path<G> for Id<T, I>
+    where G: Eval + Constrain<I>, <G as Constrain<I>>::Lift: ExPath
+{
+    type Lift = Id<G::Output, <<G as Constrain<I>>::Lift as ExPath>::Lift>;
+
+    fn path_force(&self, arg: G) -> Self::Lift {
+        Id {t: PhantomData, i: arg.i_force(self.i.clone()).ex_path()}
+    }
+}
+
+path_impl!{nat Add, Even, Eq<bool>}
+path_impl!{nat Add, Odd, Xor}
+path_impl!{nat Mul, Even, Or}
+path_impl!{nat Mul, Odd, And}
+path_impl!{nat Sub, Even, Eq<bool>}
+path_impl!{nat Sub, Odd, Xor}
+path_impl!{nat WrappingAdd, Even, Eq<bool>}
+path_impl!{nat WrappingAdd, Odd, Xor}
+path_impl!{nat CheckedAdd, Even, Eq<bool>}
+path_impl!{nat CheckedAdd, Odd, Xor}
+
+// `f[g_in -> g_out] <=> h`
+macro_rules! path_to_impl {
+    (nat $a:ident , $b:ident -> $c:ty , $h:ident) => {
+        impl<N: Nat, T: Clone> Path<($b<N>, $c)> for $a<N, T>
+            where $b<N, T>: ExPath
+        {
+            type Lift = $h<<<$b<N> as Constrain<T>>::Lift as ExPath>::Lift>;
+
+            fn path_force(&self, arg: ($b<N>, $c)) -> Self::Lift {
+                $h {i: arg.0.i_force(self.i.clone()).ex_path()}
+            }
+        }
+    };
+    (nat $a:ident , $b:ident -> $c:ty , $h:ident < $t:ident >) => {
+        impl<N: Nat, T: Clone> Path<($b<N>, $c)> for $a<N, T>
+            where $b<N, T>: ExPath
+        {
+            type Lift = $h<$t, <<$b<N> as Constrain<T>>::Lift as ExPath>::Lift>;
+
+            fn path_force(&self, arg: ($b<N>, $c)) -> Self::Lift {
+                $h {t: PhantomData, i: arg.0.i_force(self.i.clone()).ex_path()}
+            }
+        }
+    };
+}
+
+path_to_impl!{nat Even, Even -> Id<bool>, Id<bool>}
+path_to_impl!{nat Even, Odd -> Id<bool>, Not}
+path_to_impl!{nat Odd, Odd -> Id<bool>, Id<bool>}
+path_to_impl!{nat Odd, Even -> Id<bool>, Not}
+path_to_impl!{nat Add, Odd -> Even<N>, Eq<bool>}
+path_to_impl!{nat Add, Even -> Odd<N>, Xor}
+path_to_impl!{nat Mul, Even -> Odd<N>, Nor}
+path_to_impl!{nat Mul, Odd -> Even<N>, Nand}
+
+// `f[(g0, g1) -> g2] <=> h`
+macro_rules! path_args_impl {
+    ($a:ident , ($b0:ty , $b1:ty) -> $c:ty , $h:ident) => {
+        impl<T: Clone> Path<($b0, $b1, $c)> for $a<T>
+            where ($b0, $b1): Constrain<T>, <($b0, $b1) as Constrain<T>>::Lift: ExPath
+        {
+            type Lift = $h<<<($b0, $b1) as Constrain<T>>::Lift as ExPath>::Lift>;
+
+            fn path_force(&self, arg: ($b0, $b1, $c)) -> Self::Lift {
+                $h {i: (arg.0, arg.1).i_force(self.i.clone()).ex_path()}
+            }
+        }
+    };
+    (nat $a:ident , ($b0:ty , $b1:ty) -> $c:ty , $h:ident) => {
+        impl<N: Nat, T: Clone> Path<($b0, $b1, $c)> for $a<N, T>
+            where ($b0, $b1): Constrain<T>, <($b0, $b1) as Constrain<T>>::Lift: ExPath
+        {
+            type Lift = $h<<<($b0, $b1) as Constrain<T>>::Lift as ExPath>::Lift>;
+
+            fn path_force(&self, arg: ($b0, $b1, $c)) -> Self::Lift {
+                $h {i: (arg.0, arg.1).i_force(self.i.clone()).ex_path()}
+            }
+        }
+    };
+    (nat $a:ident , ($b0:ty , $b1:ty) -> $c:ty , $h:ident < $t:ident >) => {
+        impl<N: Nat, T: Clone> Path<($b0, $b1, $c)> for $a<N, T>
+            where ($b0, $b1): Constrain<T>, <($b0, $b1) as Constrain<T>>::Lift: ExPath
+        {
+            type Lift = $h<$t, <<($b0, $b1) as Constrain<T>>::Lift as ExPath>::Lift>;
+
+            fn path_force(&self, arg: ($b0, $b1, $c)) -> Self::Lift {
+                $h {t: PhantomData, i: (arg.0, arg.1).i_force(self.i.clone()).ex_path()}
+            }
+        }
+    };
+}
+
+path_args_impl!{And, (Not, Id<bool>) -> Not, Nrexc}
+path_args_impl!{And, (Id<bool>, Not) -> Not, Nexc}
+path_args_impl!{Or, (Not, Id<bool>) -> Not, Exc}
+path_args_impl!{Or, (Id<bool>, Not) -> Not, Rexc}
+path_args_impl!{nat Add, (Even<N>, Odd<N>) -> Odd<N>, Eq<bool>}
+path_args_impl!{nat Add, (Odd<N>, Even<N>) -> Odd<N>, Eq<bool>}
+path_args_impl!{nat Add, (Even<N>, Odd<N>) -> Even<N>, Xor}
+path_args_impl!{nat Add, (Odd<N>, Even<N>) -> Even<N>, Xor}
+path_args_impl!{nat Mul, (Even<N>, Odd<N>) -> Even<N>, Nrexc}
+path_args_impl!{nat Mul, (Odd<N>, Even<N>) -> Even<N>, Nexc}
+
+// `add[rem(k)] <=> add_mod(k)`
+impl<N: Nat, T: Clone> Path<RemK<N>> for Add<N, T>
+    where RemK<N, T>: ExPath
+{
+    type Lift = AddMod<N, <<RemK<N> as Constrain<T>>::Lift as ExPath>::Lift>;
+
+    fn path_force(&self, arg: RemK<N>) -> Self::Lift {
+        AddMod {k: arg.k, i: arg.i_force(self.i.clone()).ex_path()}
+    }
+}
+
+// `(g . f)[h] <=> g[h] . f[h]`
+impl<G, F, H: Clone> Path<H> for Compose<G, F>
+    where G: Path<H>, F: Path<H>
+{
+    type Lift = Compose<G::Lift, F::Lift>;
+
+    fn path_force(&self, arg: H) -> Self::Lift {
+        Compose {g: self.g.path_force(arg.clone()), f: self.f.path_force(arg)}
+    }
+}
+
+// `add(k)[even] <=> if even(k) {id} else {not}`
+// `add(k)[odd] <=> if even(k) {id} else {not}`
+macro_rules! add_k_impl {
+    ($b:ident) => {
+        impl<N: Nat, T: Clone> Path<$b<N>> for AddK<N, T>
+            where $b<N, T>: ExPath
+        {
+            type Lift = IfK<N, Even<N>, Id<bool>, Not, <<$b<N> as Constrain<T>>::Lift as ExPath>::Lift>;
+
+            fn path_force(&self, arg: $b<N>) -> Self::Lift {
+                IfK {
+                    k: self.k, co: Even::default(), tr: Id::default(), fa: Not::default(),
+                    i: arg.i_force(self.i.clone()).ex_path()
+                }
+            }
+        }
+    };
+}
+
+add_k_impl!{Even}
+add_k_impl!{Odd}
+
+// `add(K)[even] <=> if even(K) {id} else {not}`
+// `add(K)[odd] <=> if even(K) {id} else {not}`
+// The lift branches on the value of `K`, so it captures `K` at runtime.
+macro_rules! add_c_impl {
+    ($b:ident) => {
+        impl<const K: u64, T: Clone> Path<$b<u64>> for AddC<K, T>
+            where $b<u64, T>: ExPath
+        {
+            type Lift = IfK<u64, Even<u64>, Id<bool>, Not, <<$b<u64> as Constrain<T>>::Lift as ExPath>::Lift>;
+
+            fn path_force(&self, arg: $b<u64>) -> Self::Lift {
+                IfK {
+                    k: K, co: Even::default(), tr: Id::default(), fa: Not::default(),
+                    i: arg.i_force(self.i.clone()).ex_path()
+                }
+            }
+        }
+    };
+}
+
+add_c_impl!{Even}
+add_c_impl!{Odd}
+
+macro_rules! int_impl {
     ($t:ident) => {
         path_impl!{sym Add<$t>, Even<$t>, Eq<bool>}
         path_impl!{sym Add<$t>, Odd<$t>, Xor}
+        path_impl!{sym Mul<$t>, Even<$t>, Or}
+        path_impl!{sym Mul<$t>, Odd<$t>, And}
+        path_impl!{sym Neg<$t>, Even<$t>, Id<bool>}
+        path_impl!{sym Neg<$t>, Odd<$t>, Id<bool>}
+        path_impl!{sym Abs<$t>, Even<$t>, Id<bool>}
+        path_impl!{sym Abs<$t>, Odd<$t>, Id<bool>}
+
+        // `mul{(non_zero, non_zero)}[is_neg] <=> xor`
+        // Zero is not negative, so the path only exists when both arguments are non-zero.
+        impl Path<IsNeg<$t>> for Mul<$t, (NonZero<$t>, NonZero<$t>)> {
+            type Lift = Xor<<IsNeg<$t, (NonZero<$t>, NonZero<$t>)> as ExPath>::Lift>;
+
+            fn path_force(&self, arg: IsNeg<$t>) -> Self::Lift {
+                Xor {i: arg.i_force(self.i).ex_path()}
+            }
+        }
+    };
+}
+
+int_impl!{i8}
+int_impl!{i16}
+int_impl!{i32}
+int_impl!{i64}
+
+macro_rules! float_impl {
+    ($t:ident) => {
+        path_impl!{sym Neg<$t>, SignBit<$t>, Not}
+        path_impl!{sym Neg<$t>, IsNan<$t>, Id<bool>}
+        path_impl!{sym Neg<$t>, IsFinite<$t>, Id<bool>}
+        path_impl!{sym Abs<$t>, SignBit<$t>, False1<bool>}
+        path_impl!{sym Abs<$t>, IsNan<$t>, Id<bool>}
+        path_impl!{sym Abs<$t>, IsFinite<$t>, Id<bool>}
+
+        // `mul{(is_finite, is_finite)}[sign_bit] <=> xor`
+        // The sign of `0 * inf` is not specified, since the result is NaN.
+        // Finite arguments never give NaN, also when overflowing to infinity.
+        impl Path<SignBit<$t>> for Mul<$t, (IsFinite<$t>, IsFinite<$t>)> {
+            type Lift = Xor<<SignBit<$t, (IsFinite<$t>, IsFinite<$t>)> as ExPath>::Lift>;
+
+            fn path_force(&self, arg: SignBit<$t>) -> Self::Lift {
+                Xor {i: arg.i_force(self.i).ex_path()}
+            }
+        }
     };
 }
 
-nat_impl!{u8}
-nat_impl!{u16}
-nat_impl!{u32}
-nat_impl!{u64}
+float_impl!{f32}
+float_impl!{f64}
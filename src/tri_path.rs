@@ -17,6 +17,33 @@ impl<Co, Tr, Fa, I: Clone> TriPath for If<Co, Tr, Fa, I> {
     fn tri_path(&self) -> Self::Lift {self.i.clone()}
 }
 
+impl<A, B, I: Clone> TriPath for Both<A, B, I> {
+    type Lift = I;
+    fn tri_path(&self) -> Self::Lift {self.i.clone()}
+}
+
+impl<A, B, I: Clone> TriPath for Either<A, B, I> {
+    type Lift = I;
+    fn tri_path(&self) -> Self::Lift {self.i.clone()}
+}
+
+impl<A, I: Clone> TriPath for Complement<A, I> {
+    type Lift = I;
+    fn tri_path(&self) -> Self::Lift {self.i.clone()}
+}
+
+// `∀union(f) <=> ∀f`
+impl<F: TriPath> TriPath for Union<F> {
+    type Lift = F::Lift;
+    fn tri_path(&self) -> Self::Lift {self.f.tri_path()}
+}
+
+// `∀(g . f) <=> ∀f`
+impl<G, F: TriPath> TriPath for Compose<G, F> {
+    type Lift = F::Lift;
+    fn tri_path(&self) -> Self::Lift {self.f.tri_path()}
+}
+
 macro_rules! tri_path_impl {
     ($a:ident t) => {
         impl<T, I: Clone> TriPath for $a<T, I> {
@@ -24,6 +51,12 @@ macro_rules! tri_path_impl {
             fn tri_path(&self) -> Self::Lift {self.i.clone()}
         }
     };
+    ($a:ident c) => {
+        impl<const K: u64, I: Clone> TriPath for $a<K, I> {
+            type Lift = I;
+            fn tri_path(&self) -> Self::Lift {self.i.clone()}
+        }
+    };
     ($a:ident) => {
         impl<I: Clone> TriPath for $a<I> {
             type Lift = I;
@@ -39,6 +72,8 @@ tri_path_impl!{Or}
 tri_path_impl!{And}
 tri_path_impl!{Eq t}
 tri_path_impl!{EqK t}
+tri_path_impl!{Neq t}
+tri_path_impl!{NeqK t}
 tri_path_impl!{Xor}
 tri_path_impl!{Nor}
 tri_path_impl!{Nand}
@@ -48,7 +83,213 @@ tri_path_impl!{Rexc}
 tri_path_impl!{Nexc}
 tri_path_impl!{Even t}
 tri_path_impl!{Odd t}
-tri_path_impl!{Add t}
-tri_path_impl!{AddK t}
+tri_path_impl!{WrappingAdd t}
+tri_path_impl!{SaturatingAdd t}
+tri_path_impl!{AddFits t}
+tri_path_impl!{Mul t}
+tri_path_impl!{MulK t}
 tri_path_impl!{GeK t}
 tri_path_impl!{LtK t}
+tri_path_impl!{GtK t}
+tri_path_impl!{LeK t}
+tri_path_impl!{RangeK t}
+tri_path_impl!{Ge t}
+tri_path_impl!{AddMod t}
+tri_path_impl!{ModK t}
+tri_path_impl!{IsNeg t}
+tri_path_impl!{IsPos t}
+tri_path_impl!{NonZero t}
+tri_path_impl!{IsNan t}
+tri_path_impl!{IsFinite t}
+tri_path_impl!{SignBit t}
+tri_path_impl!{EqC c}
+tri_path_impl!{GeC c}
+tri_path_impl!{LtC c}
+
+// `add(K)` is only defined for `(<= max - K)`, like `add(k)`.
+impl<const K: u64, I: Clone> TriPath for AddC<K, I> {
+    type Lift = LeK<u64, I>;
+    fn tri_path(&self) -> Self::Lift {LeK {k: u64::MAX - K, i: self.i.clone()}}
+}
+
+// The sum of checked addition must be at most `max`.
+impl<T, I: Clone> TriPath for CheckedAdd<T, I> {
+    type Lift = AddFits<T, I>;
+    fn tri_path(&self) -> Self::Lift {AddFits {t: PhantomData, i: self.i.clone()}}
+}
+
+// The sum of addition must be at most `max`, like checked addition.
+impl<T: Nat, I: Clone> TriPath for Add<T, I> {
+    type Lift = AddFits<T, I>;
+    fn tri_path(&self) -> Self::Lift {AddFits {t: PhantomData, i: self.i.clone()}}
+}
+
+// `add(k)` is only defined for `(<= max - k)`.
+impl<T: Nat, I: Clone> TriPath for AddK<T, I> {
+    type Lift = LeK<T, I>;
+    fn tri_path(&self) -> Self::Lift {LeK {k: T::MAX - self.k, i: self.i.clone()}}
+}
+
+// Addition of arbitrary-precision natural numbers never overflows.
+impl<I: Clone> TriPath for Add<BigNat, I> {
+    type Lift = I;
+    fn tri_path(&self) -> Self::Lift {self.i.clone()}
+}
+
+impl<I: Clone> TriPath for AddK<BigNat, I> {
+    type Lift = I;
+    fn tri_path(&self) -> Self::Lift {self.i.clone()}
+}
+
+// The input of subtraction must be greater or equal to the subtracted value.
+impl<T, I: Clone> TriPath for Sub<T, I> {
+    type Lift = Ge<T, I>;
+    fn tri_path(&self) -> Self::Lift {Ge {t: PhantomData, i: self.i.clone()}}
+}
+
+impl<T: Clone, I: Clone> TriPath for SubK<T, I> {
+    type Lift = GeK<T, I>;
+    fn tri_path(&self) -> Self::Lift {GeK {k: self.k.clone(), i: self.i.clone()}}
+}
+
+// The divisor must be different from zero.
+impl<T: Nat> TriPath for Div<T> {
+    type Lift = ((), NeqK<T>);
+    fn tri_path(&self) -> Self::Lift {((), NeqK {k: T::ZERO, i: ()})}
+}
+
+impl<T: Nat, A: Clone, B: Clone> TriPath for Div<T, (A, B)> {
+    type Lift = (A, NeqK<T, B>);
+    fn tri_path(&self) -> Self::Lift {(self.i.0.clone(), NeqK {k: T::ZERO, i: self.i.1.clone()})}
+}
+
+impl<T: Nat> TriPath for Rem<T> {
+    type Lift = ((), NeqK<T>);
+    fn tri_path(&self) -> Self::Lift {((), NeqK {k: T::ZERO, i: ()})}
+}
+
+impl<T: Nat, A: Clone, B: Clone> TriPath for Rem<T, (A, B)> {
+    type Lift = (A, NeqK<T, B>);
+    fn tri_path(&self) -> Self::Lift {(self.i.0.clone(), NeqK {k: T::ZERO, i: self.i.1.clone()})}
+}
+
+// A constraint on both arguments also applies to the divisor.
+// Predicates are listed, because they overlap with the tuple constraints.
+macro_rules! div_rem_impl {
+    ($a:ident) => {
+        impl<T: Nat> TriPath for Div<T, $a<T>> {
+            type Lift = ($a<T>, NeqK<T, $a<T>>);
+            fn tri_path(&self) -> Self::Lift {(self.i.clone(), NeqK {k: T::ZERO, i: self.i.clone()})}
+        }
+
+        impl<T: Nat> TriPath for Rem<T, $a<T>> {
+            type Lift = ($a<T>, NeqK<T, $a<T>>);
+            fn tri_path(&self) -> Self::Lift {(self.i.clone(), NeqK {k: T::ZERO, i: self.i.clone()})}
+        }
+    };
+    ($a:ident < $($p:ident),* >) => {
+        impl<T: Nat, $($p: Clone),*> TriPath for Div<T, $a<$($p),*>> {
+            type Lift = ($a<$($p),*>, NeqK<T, $a<$($p),*>>);
+            fn tri_path(&self) -> Self::Lift {(self.i.clone(), NeqK {k: T::ZERO, i: self.i.clone()})}
+        }
+
+        impl<T: Nat, $($p: Clone),*> TriPath for Rem<T, $a<$($p),*>> {
+            type Lift = ($a<$($p),*>, NeqK<T, $a<$($p),*>>);
+            fn tri_path(&self) -> Self::Lift {(self.i.clone(), NeqK {k: T::ZERO, i: self.i.clone()})}
+        }
+    };
+}
+
+div_rem_impl!{False1}
+div_rem_impl!{Even}
+div_rem_impl!{Odd}
+div_rem_impl!{ModK}
+div_rem_impl!{EqK}
+div_rem_impl!{NeqK}
+div_rem_impl!{GeK}
+div_rem_impl!{LtK}
+div_rem_impl!{GtK}
+div_rem_impl!{LeK}
+div_rem_impl!{RangeK}
+div_rem_impl!{Both<A, B, I>}
+div_rem_impl!{Either<A, B, I>}
+div_rem_impl!{Complement<A, I>}
+div_rem_impl!{If<Co, Tr, Fa, I>}
+div_rem_impl!{IfK<K, Co, Tr, Fa, I>}
+
+// The divisor `k` must be different from zero, so `∀div(0)` and `∀rem(0)` are `false_1`.
+impl<T: Nat, I: Clone> TriPath for DivK<T, I> {
+    type Lift = IfK<T, EqK<T>, False1<T>, I>;
+    fn tri_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: False1::default(), fa: self.i.clone(), i: ()}
+    }
+}
+
+impl<T: Nat, I: Clone> TriPath for RemK<T, I> {
+    type Lift = IfK<T, EqK<T>, False1<T>, I>;
+    fn tri_path(&self) -> Self::Lift {
+        IfK {k: self.k, co: EqK {k: T::ZERO, i: ()}, tr: False1::default(), fa: self.i.clone(), i: ()}
+    }
+}
+
+// Negation and absolute value of `min` overflows.
+// The sum of addition must be in `[min, max]`.
+macro_rules! int_impl {
+    ($t:ident) => {
+        impl<I: Clone> TriPath for Add<$t, I> {
+            type Lift = AddFits<$t, I>;
+            fn tri_path(&self) -> Self::Lift {AddFits {t: PhantomData, i: self.i.clone()}}
+        }
+
+        // `\(x: int) = if k >= 0 {(<= max - k)(x)} else {(>= min - k)(x)}`
+        impl<I: Clone> TriPath for AddK<$t, I> {
+            type Lift = IfK<$t, GeK<$t>, LeK<$t, I>, GeK<$t, I>>;
+            fn tri_path(&self) -> Self::Lift {
+                IfK {
+                    k: self.k, co: GeK {k: 0, i: ()}, i: (),
+                    tr: LeK {k: $t::MAX.wrapping_sub(self.k), i: self.i.clone()},
+                    fa: GeK {k: $t::MIN.wrapping_sub(self.k), i: self.i.clone()},
+                }
+            }
+        }
+
+        impl<I: Clone> TriPath for Neg<$t, I> {
+            type Lift = NeqK<$t, I>;
+            fn tri_path(&self) -> Self::Lift {NeqK {k: $t::MIN, i: self.i.clone()}}
+        }
+
+        impl<I: Clone> TriPath for Abs<$t, I> {
+            type Lift = NeqK<$t, I>;
+            fn tri_path(&self) -> Self::Lift {NeqK {k: $t::MIN, i: self.i.clone()}}
+        }
+    };
+}
+
+int_impl!{i8}
+int_impl!{i16}
+int_impl!{i32}
+int_impl!{i64}
+
+// Negation and absolute value of floats only change the sign bit.
+// Addition of floats overflows to infinity.
+macro_rules! float_impl {
+    ($t:ident) => {
+        impl<I: Clone> TriPath for Add<$t, I> {
+            type Lift = I;
+            fn tri_path(&self) -> Self::Lift {self.i.clone()}
+        }
+
+        impl<I: Clone> TriPath for Neg<$t, I> {
+            type Lift = I;
+            fn tri_path(&self) -> Self::Lift {self.i.clone()}
+        }
+
+        impl<I: Clone> TriPath for Abs<$t, I> {
+            type Lift = I;
+            fn tri_path(&self) -> Self::Lift {self.i.clone()}
+        }
+    };
+}
+
+float_impl!{f32}
+float_impl!{f64}
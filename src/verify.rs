@@ -0,0 +1,540 @@
+//! Semantic verification of existential paths.
+//!
+//! The existential path of a function is the set of outputs it can produce.
+//! For finite input types this can be computed by evaluating the function for every input
+//! that satisfies the constraint, which is compared with the existential path returned by `ExPath`.
+
+use *;
+
+use std::fmt::Debug;
+
+/// Implemented by finite types whose values can be listed.
+pub trait Enumerate: Sized {
+    /// Returns all values in increasing order.
+    fn all() -> Vec<Self>;
+}
+
+impl Enumerate for bool {
+    fn all() -> Vec<bool> {vec![false, true]}
+}
+
+impl<A: Enumerate + Clone, B: Enumerate + Clone> Enumerate for (A, B) {
+    fn all() -> Vec<(A, B)> {
+        let bs = B::all();
+        let mut res = vec![];
+        for a in A::all() {
+            for b in &bs {
+                res.push((a.clone(), b.clone()));
+            }
+        }
+        res
+    }
+}
+
+/// An output value where the existential path disagrees with the image of a function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch {
+    /// The constrained function.
+    pub function: String,
+    /// The existential path returned by `ExPath`.
+    pub ex_path: String,
+    /// The output value.
+    pub output: String,
+    /// Whether the output value is in the image of the function.
+    pub in_image: bool,
+}
+
+/// Compares the existential path of a constrained function with its image.
+///
+/// Returns every output value where they disagree.
+pub fn verify<F>(f: &F) -> Vec<Mismatch>
+    where F: Eval + ExPath + Debug,
+          F::Input: Enumerate,
+          F::Output: Enumerate + Ord + Debug,
+          F::Lift: Check<F::Output> + Debug
+{
+    verify_window(f, F::Input::all(), &F::Output::all())
+}
+
+/// Compares the existential path of a constrained function with its image,
+/// restricted to some inputs and outputs.
+///
+/// This is used for infinite types, where every output in the window
+/// that is in the image must be reached by some input in the window.
+pub fn verify_window<F>(f: &F, inputs: Vec<F::Input>, outputs: &[F::Output]) -> Vec<Mismatch>
+    where F: Eval + ExPath + Debug,
+          F::Output: Ord + Debug,
+          F::Lift: Check<F::Output> + Debug
+{
+    let mut image: Vec<F::Output> = inputs.into_iter().filter_map(|x| f.eval(x)).collect();
+    image.sort();
+    image.dedup();
+    let ex = f.ex_path();
+    outputs.iter().filter_map(|y| {
+        let in_image = image.binary_search(y).is_ok();
+        if ex.check(y) == in_image {None} else {
+            Some(Mismatch {
+                function: format!("{:?}", f),
+                ex_path: format!("{:?}", ex),
+                output: format!("{:?}", y),
+                in_image,
+            })
+        }
+    }).collect()
+}
+
+macro_rules! verify_bool {
+    ($res:ident , $f:expr) => {{
+        let f = $f;
+        $res.extend(verify(&f));
+        $res.extend(verify(&f.i(Not::<()>::default())));
+        $res.extend(verify(&f.i(Id::<bool>::default())));
+    }};
+}
+
+macro_rules! verify_bool_bool {
+    ($res:ident , $f:ty) => {{
+        let f: $f = Default::default();
+        let not: Not = Not::default();
+        let id: Id<bool> = Id::default();
+        verify_bool!($res, f);
+        $res.extend(verify(&f.i(((), ()))));
+        $res.extend(verify(&f.i((not, ()))));
+        $res.extend(verify(&f.i(((), not))));
+        $res.extend(verify(&f.i((not, not))));
+        $res.extend(verify(&f.i((id, ()))));
+        $res.extend(verify(&f.i(((), id))));
+        $res.extend(verify(&f.i((id, id))));
+        $res.extend(verify(&f.i((not, id))));
+        $res.extend(verify(&f.i((id, not))));
+    }};
+}
+
+/// Verifies the existential paths of the Boolean algebra under all constraints.
+pub fn verify_bool() -> Vec<Mismatch> {
+    let mut res = vec![];
+    let false_1: False1<bool> = False1::default();
+    let id: Id<bool> = Id::default();
+    let not: Not = Not::default();
+    res.extend(verify(&false_1));
+    res.extend(verify(&false_1.i(not)));
+    res.extend(verify(&id));
+    res.extend(verify(&id.i(not)));
+    res.extend(verify(&id.i(id)));
+    verify_bool!(res, not);
+    verify_bool_bool!(res, And);
+    verify_bool_bool!(res, Or);
+    verify_bool_bool!(res, Eq<bool>);
+    verify_bool_bool!(res, Xor);
+    verify_bool_bool!(res, Neq<bool>);
+    verify_bool_bool!(res, Nand);
+    verify_bool_bool!(res, Nor);
+    verify_bool_bool!(res, Exc);
+    verify_bool_bool!(res, Nrexc);
+    verify_bool_bool!(res, Rexc);
+    verify_bool_bool!(res, Nexc);
+    res
+}
+
+// Verifies a function under each of the constraints.
+macro_rules! verify_i {
+    ($res:ident , $f:expr , $($i:expr),*) => {
+        $($res.extend(verify(&$f.i($i)));)*
+    };
+}
+
+macro_rules! verify_parity_bound {
+    ($res:ident , $f:expr , $p:ident , $($b:ident),*) => {
+        $(
+            $res.extend(verify(&$f.i(Both::new($p, $b))));
+            $res.extend(verify(&$f.i(Both::new($b, $p))));
+        )*
+    };
+}
+
+impl Enumerate for u8 {
+    fn all() -> Vec<u8> {(0..=u8::MAX).collect()}
+}
+
+/// Verifies the existential paths of natural numbers for `u8`.
+///
+/// Sweeps all values of the constants `k` and `k2` captured by the functions and constraints.
+pub fn verify_u8() -> Vec<Mismatch> {
+    type Nat = u8;
+    let mut res = vec![];
+    let even: Even<Nat> = Even::default();
+    let odd: Odd<Nat> = Odd::default();
+    let false_1: False1<Nat> = False1::default();
+    let id: Id<Nat> = Id::default();
+    let add: Add<Nat> = Add::default();
+    let mul: Mul<Nat> = Mul::default();
+    let sub: Sub<Nat> = Sub::default();
+    let ge: Ge<Nat> = Ge::default();
+    let div: Div<Nat> = Div::default();
+    let rem: Rem<Nat> = Rem::default();
+    res.extend(verify(&false_1));
+    res.extend(verify(&id));
+    res.extend(verify(&even));
+    res.extend(verify(&even.i(even)));
+    res.extend(verify(&even.i(odd)));
+    res.extend(verify(&odd));
+    res.extend(verify(&odd.i(odd)));
+    res.extend(verify(&odd.i(even)));
+    res.extend(verify(&add));
+    res.extend(verify(&add.i((even, odd))));
+    res.extend(verify(&add.i((odd, even))));
+    res.extend(verify(&add.i((even, even))));
+    res.extend(verify(&add.i((odd, odd))));
+    res.extend(verify(&add.i((odd, odd)).ex_path()));
+    res.extend(verify(&mul));
+    res.extend(verify(&mul.i((even, odd))));
+    res.extend(verify(&mul.i((odd, even))));
+    res.extend(verify(&mul.i((odd, odd))));
+    res.extend(verify(&mul.i(odd)));
+    res.extend(verify(&mul.i((even, even))));
+    res.extend(verify(&mul.i(even)));
+    let wrapping_add: WrappingAdd<Nat> = WrappingAdd::default();
+    let checked_add: CheckedAdd<Nat> = CheckedAdd::default();
+    let saturating_add: SaturatingAdd<Nat> = SaturatingAdd::default();
+    let add_fits: AddFits<Nat> = AddFits::default();
+    verify_i!(res, wrapping_add, (), (even, odd), (odd, even), (even, even), (odd, odd));
+    verify_i!(res, checked_add, (), (even, odd), (odd, even), (even, even), (odd, odd));
+    verify_i!(res, saturating_add, (), (even, odd), (odd, even), (even, even), (odd, odd));
+    verify_i!(res, add_fits, (), (even, odd), (odd, even), (even, even), (odd, odd));
+    res.extend(verify(&saturating_add.i((even, even)).ex_path()));
+    res.extend(verify(&saturating_add.i((odd, odd)).ex_path()));
+    res.extend(verify(&ge));
+    res.extend(verify(&div));
+    res.extend(verify(&rem));
+    res.extend(verify(&sub));
+    res.extend(verify(&sub.i(even)));
+    res.extend(verify(&sub.i(odd)));
+    res.extend(verify(&sub.i((even, even))));
+    res.extend(verify(&sub.i((odd, odd))));
+    res.extend(verify(&sub.i((even, odd))));
+    res.extend(verify(&sub.i((odd, even))));
+    res.extend(verify(&sub.i((even, odd)).ex_path()));
+    res.extend(verify(&even.i(sub.i((even, odd)).ex_path())));
+    res.extend(verify(&even.i(add.i((odd, odd)).ex_path())));
+    res.extend(verify(&add.i(Complement::new(even))));
+    res.extend(verify(&add.i(Complement::new(odd))));
+    res.extend(verify(&add.i(Either::new(even, odd))));
+    for k in Nat::all() {
+        let eq_k = EqK {k, i: ()};
+        let ge_k = GeK {k, i: ()};
+        let lt_k = LtK {k, i: ()};
+        let neq_k = NeqK {k, i: ()};
+        let gt_k = GtK {k, i: ()};
+        let le_k = LeK {k, i: ()};
+        let add_k = AddK {k, i: ()};
+        let mul_k = MulK {k, i: ()};
+        let sub_k = SubK {k, i: ()};
+        let div_k = DivK {k, i: ()};
+        let rem_k = RemK {k, i: ()};
+        res.extend(verify(&eq_k));
+        res.extend(verify(&eq_k.i(even)));
+        res.extend(verify(&eq_k.i(odd)));
+        res.extend(verify(&ge_k));
+        res.extend(verify(&ge_k.i(even)));
+        res.extend(verify(&ge_k.i(odd)));
+        res.extend(verify(&lt_k));
+        res.extend(verify(&lt_k.i(even)));
+        res.extend(verify(&lt_k.i(odd)));
+        res.extend(verify(&gt_k));
+        res.extend(verify(&gt_k.i(even)));
+        res.extend(verify(&gt_k.i(odd)));
+        res.extend(verify(&le_k));
+        res.extend(verify(&le_k.i(even)));
+        res.extend(verify(&le_k.i(odd)));
+        res.extend(verify(&even.i(gt_k)));
+        res.extend(verify(&even.i(le_k)));
+        res.extend(verify(&odd.i(gt_k)));
+        res.extend(verify(&odd.i(le_k)));
+        res.extend(verify(&neq_k));
+        res.extend(verify(&neq_k.i(even)));
+        res.extend(verify(&neq_k.i(odd)));
+        res.extend(verify(&sub_k));
+        res.extend(verify(&sub_k.i(even)));
+        res.extend(verify(&sub_k.i(odd)));
+        res.extend(verify(&div_k));
+        res.extend(verify(&rem_k));
+        res.extend(verify(&add_k));
+        res.extend(verify(&add_k.i(even)));
+        res.extend(verify(&add_k.i(odd)));
+        res.extend(verify(&mul_k));
+        res.extend(verify(&mul_k.i(even)));
+        res.extend(verify(&mul_k.i(odd)));
+        res.extend(verify(&mul.i(eq_k)));
+        res.extend(verify(&even.i(eq_k)));
+        res.extend(verify(&even.i(ge_k)));
+        res.extend(verify(&even.i(lt_k)));
+        res.extend(verify(&odd.i(eq_k)));
+        res.extend(verify(&odd.i(ge_k)));
+        res.extend(verify(&odd.i(lt_k)));
+        res.extend(verify(&even.i(neq_k)));
+        res.extend(verify(&odd.i(neq_k)));
+        res.extend(verify(&Compose::new(even, add_k)));
+        res.extend(verify(&Compose::new(odd, add_k)));
+        res.extend(verify(&Compose::new(even, rem_k)));
+        res.extend(verify(&Compose::new(add_k, add_k)));
+
+        res.extend(verify(&If {co: lt_k, tr: false_1, fa: even, i: ()}));
+        res.extend(verify(&Union::new(If {co: lt_k, tr: false_1, fa: even, i: ()})));
+        res.extend(verify(&Union::new(If {co: lt_k, tr: false_1, fa: even, i: odd})));
+
+        // Constraint combinators.
+        res.extend(verify(&even.i(Complement::new(ge_k))));
+        res.extend(verify(&odd.i(Complement::new(eq_k))));
+        res.extend(verify(&add_k.i(Complement::new(lt_k))));
+        res.extend(verify(&eq_k.i(Complement::new(even))));
+        res.extend(verify(&ge_k.i(Complement::new(odd))));
+        res.extend(verify(&lt_k.i(Complement::new(le_k))));
+        res.extend(verify(&even.i(Both::new(even, ge_k))));
+        res.extend(verify(&even.i(Both::new(lt_k, odd))));
+        res.extend(verify(&odd.i(Both::new(even, eq_k))));
+        res.extend(verify(&odd.i(Both::new(odd, lt_k))));
+        res.extend(verify(&odd.i(Both::new(ge_k, even))));
+        res.extend(verify(&even.i(Either::new(eq_k, odd))));
+        res.extend(verify(&add_k.i(Either::new(even, lt_k))));
+        verify_parity_bound!(res, add, even, eq_k, ge_k, lt_k);
+        verify_parity_bound!(res, add, odd, eq_k, ge_k, lt_k);
+
+        let if_ge_even_odd = If {co: ge_k, tr: even, fa: odd, i: ()};
+        let if_ge_odd_even = If {co: ge_k, tr: odd, fa: even, i: ()};
+        let if_lt_even_odd = If {co: lt_k, tr: even, fa: odd, i: ()};
+        let if_lt_odd_even = If {co: lt_k, tr: odd, fa: even, i: ()};
+        verify_i!(res, if_ge_even_odd, (), even, odd);
+        verify_i!(res, if_ge_odd_even, (), even, odd);
+        verify_i!(res, if_lt_even_odd, (), even, odd);
+        verify_i!(res, if_lt_odd_even, (), even, odd);
+
+        for k2 in Nat::all() {
+            let eq_k2 = EqK {k: k2, i: ()};
+            let ge_k2 = GeK {k: k2, i: ()};
+            let lt_k2 = LtK {k: k2, i: ()};
+            let neq_k2 = NeqK {k: k2, i: ()};
+            let gt_k2 = GtK {k: k2, i: ()};
+            let le_k2 = LeK {k: k2, i: ()};
+            res.extend(verify(&eq_k.i(eq_k2)));
+            res.extend(verify(&eq_k.i(ge_k2)));
+            res.extend(verify(&eq_k.i(lt_k2)));
+            res.extend(verify(&ge_k.i(eq_k2)));
+            res.extend(verify(&ge_k.i(ge_k2)));
+            res.extend(verify(&ge_k.i(lt_k2)));
+            res.extend(verify(&lt_k.i(eq_k2)));
+            res.extend(verify(&lt_k.i(ge_k2)));
+            res.extend(verify(&lt_k.i(lt_k2)));
+            verify_i!(res, gt_k, eq_k2, ge_k2, lt_k2, gt_k2, le_k2);
+            verify_i!(res, le_k, eq_k2, ge_k2, lt_k2, gt_k2, le_k2);
+            verify_i!(res, eq_k, gt_k2, le_k2);
+            verify_i!(res, ge_k, gt_k2, le_k2);
+            verify_i!(res, lt_k, gt_k2, le_k2);
+            verify_i!(res, add_k, gt_k2, le_k2);
+            res.extend(verify(&eq_k.i(neq_k2)));
+            res.extend(verify(&ge_k.i(neq_k2)));
+            res.extend(verify(&lt_k.i(neq_k2)));
+            res.extend(verify(&neq_k.i(eq_k2)));
+            res.extend(verify(&neq_k.i(ge_k2)));
+            res.extend(verify(&neq_k.i(lt_k2)));
+            res.extend(verify(&neq_k.i(neq_k2)));
+            res.extend(verify(&add_k.i(eq_k2)));
+            res.extend(verify(&add_k.i(ge_k2)));
+            res.extend(verify(&add_k.i(lt_k2)));
+            res.extend(verify(&even.i(Both::new(ge_k, lt_k2))));
+            res.extend(verify(&add_k.i(Both::new(lt_k2, ge_k))));
+            res.extend(verify(&ge_k.i(Either::new(eq_k2, lt_k2))));
+            // A parity with a bound, in both orders.
+            verify_parity_bound!(res, eq_k, even, eq_k2, ge_k2, lt_k2);
+            verify_parity_bound!(res, eq_k, odd, eq_k2, ge_k2, lt_k2);
+            verify_parity_bound!(res, ge_k, even, eq_k2, ge_k2, lt_k2);
+            verify_parity_bound!(res, ge_k, odd, eq_k2, ge_k2, lt_k2);
+            verify_parity_bound!(res, lt_k, even, eq_k2, ge_k2, lt_k2);
+            verify_parity_bound!(res, lt_k, odd, eq_k2, ge_k2, lt_k2);
+            verify_parity_bound!(res, add_k, even, eq_k2, ge_k2, lt_k2);
+            verify_parity_bound!(res, add_k, odd, eq_k2, ge_k2, lt_k2);
+            // Ranges `[k, k2)`, with constants at the edges of the range.
+            let range_k = RangeK {lo: k, hi: k2, i: ()};
+            res.extend(verify(&range_k));
+            res.extend(verify(&even.i(range_k)));
+            res.extend(verify(&odd.i(range_k)));
+            for &k3 in &[k.wrapping_sub(1), k, k.wrapping_add(1), k2.wrapping_sub(1), k2, k2.wrapping_add(1)] {
+                res.extend(verify(&EqK {k: k3, i: range_k}));
+                res.extend(verify(&GeK {k: k3, i: range_k}));
+                res.extend(verify(&LtK {k: k3, i: range_k}));
+            }
+            // `!k == max - k`
+            for &k3 in &[0, !k, (!k).wrapping_add(1), !k2, (!k2).wrapping_add(1)] {
+                res.extend(verify(&AddK {k: k3, i: range_k}));
+            }
+            res.extend(verify(&sub_k.i(eq_k2)));
+            res.extend(verify(&sub_k.i(ge_k2)));
+            res.extend(verify(&sub_k.i(lt_k2)));
+            res.extend(verify(&div_k.i(eq_k2)));
+            res.extend(verify(&div_k.i(ge_k2)));
+            res.extend(verify(&div_k.i(lt_k2)));
+            res.extend(verify(&rem_k.i(eq_k2)));
+            res.extend(verify(&rem_k.i(ge_k2)));
+            res.extend(verify(&rem_k.i(lt_k2)));
+            res.extend(verify(&mul_k.i(eq_k2)));
+            res.extend(verify(&mul_k.i(ge_k2)));
+            res.extend(verify(&mul_k.i(lt_k2)));
+            res.extend(verify(&mul.i((eq_k, eq_k2))));
+            // The existential paths of `∃add(k){even}` and `∃add(k){odd}` for any `k2`.
+            res.extend(verify(&If {
+                co: ge_k, tr: IfK {k: k2, co: even, tr: even, fa: odd, i: ()}, fa: false_1, i: ()
+            }));
+            res.extend(verify(&If {
+                co: ge_k, tr: IfK {k: k2, co: odd, tr: even, fa: odd, i: ()}, fa: false_1, i: ()
+            }));
+            verify_i!(res, if_ge_even_odd, eq_k2, ge_k2, lt_k2);
+            verify_i!(res, if_ge_odd_even, eq_k2, ge_k2, lt_k2);
+            verify_i!(res, if_lt_even_odd, eq_k2, ge_k2, lt_k2);
+            verify_i!(res, if_lt_odd_even, eq_k2, ge_k2, lt_k2);
+            // Residues `(% k = k2)`, with constants at the edges of overflow.
+            let mod_k = ModK {m: k, r: k2, i: ()};
+            res.extend(verify(&mod_k));
+            let (div_k, div_k2) = (Nat::MAX / k.max(1), Nat::MAX / k2.max(1));
+            for &k3 in &[0, 1, 2, !k2, (!k2).wrapping_add(1),
+                         div_k, div_k.wrapping_add(1), div_k2, div_k2.wrapping_add(1)] {
+                res.extend(verify(&AddK {k: k3, i: mod_k}));
+                res.extend(verify(&MulK {k: k3, i: mod_k}));
+            }
+        }
+    }
+    // Sums of two inputs with the same residue.
+    for m in (0..12).chain(vec![126, 127, 128, 255]) {
+        for r in (0..13).chain(vec![63, 64, 127, 128]) {
+            res.extend(verify(&add.i(ModK {m, r, i: ()})));
+        }
+    }
+    for k in (0..12).chain(vec![126, 127, 128, 255]) {
+        let k: Nat = k;
+        let add_mod = AddMod {k, i: ()};
+        res.extend(verify(&add_mod));
+        for &k2 in &[0, 1, 2, k / 2, k / 2 + 1, k.wrapping_sub(1), k, k.wrapping_add(1), 255] {
+            res.extend(verify(&add_mod.i(LtK {k: k2, i: ()})));
+        }
+    }
+    res
+}
+
+impl Enumerate for i8 {
+    fn all() -> Vec<i8> {(i8::MIN..=i8::MAX).collect()}
+}
+
+/// Verifies the existential paths of signed integers for `i8`.
+///
+/// Sweeps all values of the constant `k` captured by the functions and constraints.
+pub fn verify_i8() -> Vec<Mismatch> {
+    type Int = i8;
+    let mut res = vec![];
+    let even: Even<Int> = Even::default();
+    let odd: Odd<Int> = Odd::default();
+    let add: Add<Int> = Add::default();
+    let mul: Mul<Int> = Mul::default();
+    let neg: Neg<Int> = Neg::default();
+    let abs: Abs<Int> = Abs::default();
+    let is_neg: IsNeg<Int> = IsNeg::default();
+    let is_pos: IsPos<Int> = IsPos::default();
+    let non_zero: NonZero<Int> = NonZero::default();
+    verify_i!(res, even, (), even, odd);
+    verify_i!(res, odd, (), even, odd);
+    verify_i!(res, add, (), (even, odd), (odd, even), (even, even), (odd, odd));
+    verify_i!(res, mul, (), (even, odd), (odd, even), (odd, odd), odd);
+    verify_i!(res, neg, (), even, odd, Complement::new(is_neg));
+    verify_i!(res, abs, (), even, odd);
+    verify_i!(res, is_neg, (), even, odd);
+    verify_i!(res, is_pos, (), even, odd);
+    verify_i!(res, is_neg, non_zero);
+    verify_i!(res, non_zero, (), even, odd);
+    verify_i!(res, mul, (non_zero, non_zero));
+    for k in Int::all() {
+        let neq_k = NeqK {k, i: ()};
+        res.extend(verify(&AddK {k, i: ()}));
+        verify_i!(res, even, neq_k);
+        verify_i!(res, odd, neq_k);
+        verify_i!(res, is_neg, neq_k);
+        verify_i!(res, is_pos, neq_k);
+        verify_i!(res, even, Both::new(Complement::new(is_pos), neq_k));
+        verify_i!(res, odd, Both::new(Complement::new(is_pos), neq_k));
+        let eq_k = EqK {k, i: ()};
+        let ge_k = GeK {k, i: ()};
+        let lt_k = LtK {k, i: ()};
+        verify_i!(res, eq_k, even, odd);
+        verify_i!(res, ge_k, (), even, odd);
+        verify_i!(res, lt_k, (), even, odd);
+        verify_i!(res, even, eq_k, ge_k, lt_k);
+        verify_i!(res, odd, eq_k, ge_k, lt_k);
+        // Constants at the edges of the domain and next to `k`.
+        for &k2 in &[Int::MIN, Int::MIN + 1, Int::MIN + 2, k.wrapping_sub(1), k, k.wrapping_add(1),
+                     Int::MAX - 1, Int::MAX] {
+            let eq_k2 = EqK {k: k2, i: ()};
+            let ge_k2 = GeK {k: k2, i: ()};
+            let lt_k2 = LtK {k: k2, i: ()};
+            verify_i!(res, eq_k, eq_k2, ge_k2, lt_k2);
+            verify_i!(res, ge_k, eq_k2, ge_k2, lt_k2);
+            verify_i!(res, lt_k, eq_k2, ge_k2, lt_k2);
+        }
+    }
+    res
+}
+
+// Verifies functions on the same window of inputs and outputs.
+macro_rules! verify_window {
+    ($res:ident , $xs:expr , $ys:expr , $($f:expr),*) => {
+        $($res.extend(verify_window(&$f, $xs.clone(), &$ys));)*
+    };
+}
+
+/// Verifies the existential paths of `BigNat` on a window of small numbers.
+///
+/// Inputs and outputs are taken from `[0, 40)`, while the constants `k` and `k2` are swept over `[0, 12)`.
+/// Addition never returns a number less than its arguments,
+/// so every output in the window that is in the image is reached by an input in the window.
+pub fn verify_big_nat() -> Vec<Mismatch> {
+    let nats: Vec<BigNat> = (0..40u8).map(BigNat::from).collect();
+    let pairs: Vec<(BigNat, BigNat)> = nats.iter()
+        .flat_map(|a| nats.iter().map(move |b| (a.clone(), b.clone()))).collect();
+    let bools = bool::all();
+    let mut res = vec![];
+    // `BigNat` is not `Copy`, so neither are the functions and constraints.
+    let even: Even<BigNat> = Even::default();
+    let odd: Odd<BigNat> = Odd::default();
+    let add: Add<BigNat> = Add::default();
+    let (ev, od) = (|| even.clone(), || odd.clone());
+    verify_window!(res, nats, bools, ev(), ev().i(ev()), ev().i(od()), od(), od().i(od()), od().i(ev()));
+    verify_window!(res, pairs, nats, add, add.i((ev(), od())), add.i((od(), ev())),
+                   add.i((ev(), ev())), add.i((od(), od())));
+    verify_window!(res, nats, bools, add.i((od(), od())).ex_path(), ev().i(add.i((od(), od())).ex_path()));
+    for k in 0..12u8 {
+        let k = BigNat::from(k);
+        let eq_k = EqK {k: k.clone(), i: ()};
+        let ge_k = GeK {k: k.clone(), i: ()};
+        let lt_k = LtK {k: k.clone(), i: ()};
+        let add_k = AddK {k, i: ()};
+        verify_window!(res, nats, bools,
+                       eq_k, eq_k.i(ev()), eq_k.i(od()), ge_k, ge_k.i(ev()), ge_k.i(od()),
+                       lt_k, lt_k.i(ev()), lt_k.i(od()),
+                       ev().i(eq_k.clone()), ev().i(ge_k.clone()), ev().i(lt_k.clone()),
+                       od().i(eq_k.clone()), od().i(ge_k.clone()), od().i(lt_k.clone()),
+                       add_k.i(ev()).ex_path(), add_k.i(od()).ex_path(),
+                       If {co: ge_k.clone(), tr: ev(), fa: od(), i: ()},
+                       If {co: ge_k.clone(), tr: od(), fa: ev(), i: ()},
+                       If {co: lt_k.clone(), tr: ev(), fa: od(), i: ()},
+                       If {co: lt_k.clone(), tr: od(), fa: ev(), i: ()});
+        verify_window!(res, nats, nats, add_k, add_k.i(ev()), add_k.i(od()));
+        for k2 in 0..12u8 {
+            let k2 = BigNat::from(k2);
+            let eq_k2 = EqK {k: k2.clone(), i: ()};
+            let ge_k2 = GeK {k: k2.clone(), i: ()};
+            let lt_k2 = LtK {k: k2, i: ()};
+            verify_window!(res, nats, bools,
+                           eq_k.i(eq_k2.clone()), eq_k.i(ge_k2.clone()), eq_k.i(lt_k2.clone()),
+                           ge_k.i(eq_k2.clone()), ge_k.i(ge_k2.clone()), ge_k.i(lt_k2.clone()),
+                           lt_k.i(eq_k2.clone()), lt_k.i(ge_k2.clone()), lt_k.i(lt_k2.clone()),
+                           add_k.i(lt_k2.clone()).ex_path());
+            verify_window!(res, nats, nats, add_k.i(eq_k2), add_k.i(ge_k2), add_k.i(lt_k2));
+        }
+    }
+    res
+}